@@ -0,0 +1,188 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+#[cfg_attr(not(feature = "bindgen"), allow(dead_code))]
+struct SolverLibrary {
+    env_prefix: &'static str,
+    home: &'static str,
+    name: &'static str,
+    bindings: &'static str,
+    header: &'static str,
+    allowlist: &'static str,
+}
+
+const SOLVER_LIBRARIES: [SolverLibrary; 3] = [
+    SolverLibrary {
+        env_prefix: "GLPK",
+        home: "GLPK_HOME",
+        name: "glpk",
+        bindings: "glpk.rs",
+        header: "glpk.h",
+        allowlist: "glp_.*|GLP_.*|_glp_.*",
+    },
+    SolverLibrary {
+        env_prefix: "SCIP",
+        home: "SCIPOPTDIR",
+        name: "scip",
+        bindings: "scip.rs",
+        header: "scip/scip.h",
+        allowlist: "SCIP.*|Scip.*",
+    },
+    SolverLibrary {
+        env_prefix: "GUROBI",
+        home: "GUROBI_HOME",
+        name: "gurobi81",
+        bindings: "gurobi.rs",
+        header: "gurobi_c.h",
+        allowlist: "GRB.*|_GRB.*",
+    },
+];
+
+// checked-in bindings ported by hand from the ones of another target rather than
+// generated from the headers on it, to be replaced by bindgen output
+const PORTED_TARGETS: [&str; 1] = ["x86_64-unknown-linux-gnu"];
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=bindings");
+    println!("cargo:rerun-if-env-changed=OSPF_UPDATE_BINDINGS");
+
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+    for library in SOLVER_LIBRARIES.iter() {
+        let lib_env = format!("{}_LIB", library.env_prefix);
+        let lib_dir_env = format!("{}_LIB_DIR", library.env_prefix);
+        let include_dir_env = format!("{}_INCLUDE_DIR", library.env_prefix);
+        println!("cargo:rerun-if-env-changed={}", lib_env);
+        println!("cargo:rerun-if-env-changed={}", lib_dir_env);
+        println!("cargo:rerun-if-env-changed={}", include_dir_env);
+        println!("cargo:rerun-if-env-changed={}", library.home);
+
+        if env::var_os(format!("CARGO_FEATURE_{}", library.env_prefix)).is_none() {
+            continue;
+        }
+
+        let include_dir = env::var(&include_dir_env)
+            .ok()
+            .map(PathBuf::from)
+            .or_else(|| {
+                env::var(library.home)
+                    .ok()
+                    .map(|home| Path::new(&home).join("include"))
+            });
+        generate_bindings(library, include_dir, &out_dir.join(library.bindings));
+
+        // in dynamic mode the solver libraries are opened at runtime, nothing to link
+        if env::var_os("CARGO_FEATURE_DYNAMIC").is_some() {
+            continue;
+        }
+        if let Ok(dir) = env::var(&lib_dir_env) {
+            println!("cargo:rustc-link-search=native={}", dir);
+        } else if let Ok(home) = env::var(library.home) {
+            println!("cargo:rustc-link-search=native={}/lib", home);
+        }
+        let name = env::var(&lib_env).unwrap_or_else(|_| {
+            if library.name == "scip" && env::var("CARGO_CFG_TARGET_OS").as_deref() == Ok("windows")
+            {
+                String::from("libscip")
+            } else {
+                String::from(library.name)
+            }
+        });
+        println!("cargo:rustc-link-lib=dylib={}", name);
+    }
+}
+
+fn generate_bindings(library: &SolverLibrary, include_dir: Option<PathBuf>, out_path: &Path) {
+    if let Some(include_dir) = include_dir {
+        let header = include_dir.join(library.header);
+        if header.exists() {
+            println!("cargo:rerun-if-changed={}", header.display());
+            if run_bindgen(library, &include_dir, &header, out_path) {
+                if env::var_os("OSPF_UPDATE_BINDINGS").is_some() {
+                    update_bindings(library, out_path);
+                }
+                return;
+            }
+        }
+    }
+    copy_bindings(library, out_path);
+}
+
+#[cfg(feature = "bindgen")]
+fn run_bindgen(
+    library: &SolverLibrary,
+    include_dir: &Path,
+    header: &Path,
+    out_path: &Path,
+) -> bool {
+    let bindings = bindgen::Builder::default()
+        .header(header.to_string_lossy())
+        .clang_arg(format!("-I{}", include_dir.display()))
+        .allowlist_function(library.allowlist)
+        .allowlist_type(library.allowlist)
+        .allowlist_var(library.allowlist)
+        .size_t_is_usize(true)
+        .layout_tests(false)
+        .generate();
+    match bindings {
+        Ok(bindings) => {
+            bindings.write_to_file(out_path).unwrap_or_else(|err| {
+                panic!("cannot write bindings {}: {}", out_path.display(), err)
+            });
+            true
+        }
+        Err(_) => {
+            println!(
+                "cargo:warning=bindgen failed on {}, using checked-in bindings",
+                header.display()
+            );
+            false
+        }
+    }
+}
+
+#[cfg(not(feature = "bindgen"))]
+fn run_bindgen(_: &SolverLibrary, _: &Path, _: &Path, _: &Path) -> bool {
+    false
+}
+
+// writes freshly generated bindings back as the checked-in ones of the target
+fn update_bindings(library: &SolverLibrary, out_path: &Path) {
+    let target = env::var("TARGET").unwrap();
+    let dir = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap())
+        .join("bindings")
+        .join(&target);
+    let path = dir.join(library.bindings);
+    fs::create_dir_all(&dir)
+        .and_then(|_| fs::copy(out_path, &path))
+        .unwrap_or_else(|err| panic!("cannot update bindings {}: {}", path.display(), err));
+}
+
+fn copy_bindings(library: &SolverLibrary, out_path: &Path) {
+    let target = env::var("TARGET").unwrap();
+    let bindings_dir = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap()).join("bindings");
+    // the layouts of FILE, va_list and the like differ between targets,
+    // so there is no fallback to the bindings of another one
+    let path = bindings_dir.join(&target).join(library.bindings);
+    if !path.exists() {
+        panic!(
+            "no {} bindings for target {}, set {}_INCLUDE_DIR and enable feature \"bindgen\"",
+            library.name, target, library.env_prefix
+        );
+    }
+    if PORTED_TARGETS.contains(&target.as_str()) {
+        println!(
+            "cargo:warning={} bindings for target {} are not generated from its headers, set {}_INCLUDE_DIR and enable feature \"bindgen\" to generate them",
+            library.name, target, library.env_prefix
+        );
+    }
+    fs::copy(&path, out_path).unwrap_or_else(|err| {
+        panic!(
+            "cannot copy bindings {} to {}: {}",
+            path.display(),
+            out_path.display(),
+            err
+        )
+    });
+}
@@ -0,0 +1,102 @@
+use super::error::SolverError;
+use ospf_rust_base::ErrorCode;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Verbosity {
+    Quiet,
+    Error,
+    Normal,
+    Detailed,
+}
+
+fn check_gap(gap: f64, kind: &str) -> Result<f64, SolverError> {
+    if gap >= 0. {
+        Ok(gap)
+    } else {
+        Err(SolverError::new(
+            ErrorCode::OREngineModelingException,
+            format!("The {} gap should be non-negative, not {}.", kind, gap),
+        ))
+    }
+}
+
+// options left as None keep the solver's own defaults
+#[derive(Clone, Debug, Default)]
+pub struct SolverConfig {
+    pub time_limit: Option<Duration>,
+    pub relative_gap: Option<f64>,
+    pub absolute_gap: Option<f64>,
+    pub threads: Option<usize>,
+    pub seed: Option<i32>,
+    pub verbosity: Option<Verbosity>,
+    pub log_file: Option<PathBuf>,
+}
+
+impl SolverConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_time_limit(mut self, time_limit: Duration) -> Self {
+        self.time_limit = Option::Some(time_limit);
+        self
+    }
+
+    pub fn with_relative_gap(mut self, gap: f64) -> Result<Self, SolverError> {
+        self.relative_gap = Option::Some(check_gap(gap, "relative")?);
+        Ok(self)
+    }
+
+    pub fn with_absolute_gap(mut self, gap: f64) -> Result<Self, SolverError> {
+        self.absolute_gap = Option::Some(check_gap(gap, "absolute")?);
+        Ok(self)
+    }
+
+    pub fn with_threads(mut self, threads: usize) -> Self {
+        self.threads = Option::Some(threads);
+        self
+    }
+
+    pub fn with_seed(mut self, seed: i32) -> Self {
+        self.seed = Option::Some(seed);
+        self
+    }
+
+    pub fn with_verbosity(mut self, verbosity: Verbosity) -> Self {
+        self.verbosity = Option::Some(verbosity);
+        self
+    }
+
+    pub fn with_log_file<P: AsRef<Path>>(mut self, path: P) -> Self {
+        self.log_file = Option::Some(path.as_ref().to_path_buf());
+        self
+    }
+
+    // time limit in milliseconds, saturated to the range of a C int
+    pub fn time_limit_millis(&self) -> Option<i32> {
+        self.time_limit
+            .map(|time_limit| time_limit.as_millis().min(i32::MAX as u128) as i32)
+    }
+}
+
+#[test]
+fn test_solver_config_gap() {
+    let config = SolverConfig::new()
+        .with_relative_gap(0.01)
+        .unwrap()
+        .with_absolute_gap(0.)
+        .unwrap();
+    assert_eq!(config.relative_gap, Option::Some(0.01));
+    assert_eq!(config.absolute_gap, Option::Some(0.));
+    assert_eq!(
+        SolverConfig::new()
+            .with_relative_gap(-0.1)
+            .err()
+            .unwrap()
+            .code(),
+        ErrorCode::OREngineModelingException
+    );
+    assert!(SolverConfig::new().with_absolute_gap(f64::NAN).is_err());
+}
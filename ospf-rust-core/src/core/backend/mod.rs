@@ -1 +1,12 @@
+pub mod config;
+pub mod error;
+pub mod output;
+pub mod solver;
+pub mod solver_registry;
 pub mod solvers;
+
+pub use config::{SolverConfig, Verbosity};
+pub use error::SolverError;
+pub use output::{SolverOutput, SolverStatus};
+pub use solver::Solver;
+pub use solver_registry::SolverRegistry;
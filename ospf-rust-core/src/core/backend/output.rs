@@ -0,0 +1,177 @@
+use super::error::SolverError;
+use ospf_rust_base::ErrorCode;
+use std::fmt;
+use std::fmt::Display;
+use std::time::Duration;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SolverStatus {
+    Optimal,
+    Feasible,
+    Infeasible,
+    Unbounded,
+    InfeasibleOrUnbounded,
+    TimeLimit,
+    NodeLimit,
+    Interrupted,
+    Error,
+}
+
+impl SolverStatus {
+    pub fn is_optimal(&self) -> bool {
+        *self == SolverStatus::Optimal
+    }
+
+    // the solver stopped before proving optimality, a solution may be available or not
+    pub fn is_terminated(&self) -> bool {
+        match self {
+            SolverStatus::TimeLimit | SolverStatus::NodeLimit | SolverStatus::Interrupted => true,
+            _ => false,
+        }
+    }
+
+    // the code reported when the outcome has no solution
+    pub fn error_code(&self) -> Option<ErrorCode> {
+        match self {
+            SolverStatus::Infeasible | SolverStatus::InfeasibleOrUnbounded => {
+                Option::Some(ErrorCode::ORModelNoSolution)
+            }
+            SolverStatus::Unbounded => Option::Some(ErrorCode::ORModelUnbounded),
+            SolverStatus::TimeLimit | SolverStatus::NodeLimit | SolverStatus::Interrupted => {
+                Option::Some(ErrorCode::OREngineTerminated)
+            }
+            SolverStatus::Error => Option::Some(ErrorCode::OREngineSolvingException),
+            SolverStatus::Optimal | SolverStatus::Feasible => Option::None,
+        }
+    }
+}
+
+impl Display for SolverStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+pub fn relative_gap(objective: f64, best_bound: f64) -> f64 {
+    if objective.is_nan() || best_bound.is_nan() {
+        f64::NAN
+    } else if objective == best_bound {
+        0.
+    } else if objective == 0. || objective.is_infinite() || best_bound.is_infinite() {
+        f64::INFINITY
+    } else {
+        (objective - best_bound).abs() / objective.abs()
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct SolverOutput {
+    pub status: SolverStatus,
+    pub objective: f64,
+    pub best_bound: f64,
+    pub gap: f64,
+    pub time: Duration,
+    pub nodes: u64,
+    pub solution: Vec<f64>,
+}
+
+impl SolverOutput {
+    pub fn new(status: SolverStatus) -> Self {
+        Self {
+            status: status,
+            objective: f64::NAN,
+            best_bound: f64::NAN,
+            gap: f64::NAN,
+            time: Duration::ZERO,
+            nodes: 0,
+            solution: Vec::new(),
+        }
+    }
+
+    // optimal and feasible outcomes have a solution, possibly of no column, while a
+    // terminated one has it only if an incumbent was found, whose objective is then set
+    pub fn has_solution(&self) -> bool {
+        match self.status {
+            SolverStatus::Optimal | SolverStatus::Feasible => true,
+            status if status.is_terminated() => !self.objective.is_nan(),
+            _ => false,
+        }
+    }
+
+    // outcomes without any primal solution are reported as errors
+    pub fn into_result(self) -> Result<Self, SolverError> {
+        if self.has_solution() {
+            return Ok(self);
+        }
+        let code = match self.status.error_code() {
+            Option::Some(code) => code,
+            Option::None => ErrorCode::ORModelNoSolution,
+        };
+        Err(SolverError::new(
+            code,
+            format!(
+                "Solver finished with status {} and no solution.",
+                self.status
+            ),
+        ))
+    }
+}
+
+impl Display for SolverOutput {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}, objective: {}, best bound: {}, gap: {}, time: {:?}, nodes: {}",
+            self.status, self.objective, self.best_bound, self.gap, self.time, self.nodes
+        )
+    }
+}
+
+#[test]
+fn test_solver_output_error_code() {
+    // a model without columns has an empty solution
+    assert!(SolverOutput::new(SolverStatus::Optimal)
+        .into_result()
+        .is_ok());
+    assert_eq!(
+        SolverOutput::new(SolverStatus::Infeasible)
+            .into_result()
+            .unwrap_err()
+            .code(),
+        ErrorCode::ORModelNoSolution
+    );
+    assert_eq!(
+        SolverOutput::new(SolverStatus::Unbounded)
+            .into_result()
+            .unwrap_err()
+            .code(),
+        ErrorCode::ORModelUnbounded
+    );
+
+    assert_eq!(
+        SolverOutput::new(SolverStatus::TimeLimit)
+            .into_result()
+            .unwrap_err()
+            .code(),
+        ErrorCode::OREngineTerminated
+    );
+    assert_eq!(
+        SolverOutput::new(SolverStatus::Interrupted)
+            .into_result()
+            .unwrap_err()
+            .code(),
+        ErrorCode::OREngineTerminated
+    );
+
+    let mut output = SolverOutput::new(SolverStatus::TimeLimit);
+    output.objective = 3.;
+    output.solution = vec![1., 2.];
+    assert!(output.into_result().is_ok());
+}
+
+#[test]
+fn test_relative_gap() {
+    assert_eq!(relative_gap(10., 10.), 0.);
+    assert_eq!(relative_gap(10., 8.), 0.2);
+    assert_eq!(relative_gap(0., -1.), f64::INFINITY);
+}
@@ -0,0 +1,88 @@
+use super::config::SolverConfig;
+use super::error::SolverError;
+use super::output::SolverOutput;
+use crate::core::frontend::model::{BinaryExpansion, LinearMetaModel, QuadraticMetaModel};
+use ospf_rust_base::ErrorCode;
+
+pub trait Solver {
+    fn name(&self) -> &'static str;
+
+    // the config replaces the one of a previous solve, options left as None are back
+    // to the defaults of the solver
+    fn solve(
+        &mut self,
+        model: &LinearMetaModel,
+        config: &SolverConfig,
+    ) -> Result<SolverOutput, SolverError>;
+
+    // solvers without quadratic support keep this default
+    fn solve_quadratic(
+        &mut self,
+        _model: &QuadraticMetaModel,
+        _config: &SolverConfig,
+    ) -> Result<SolverOutput, SolverError> {
+        Err(SolverError::new(
+            ErrorCode::OREngineModelingException,
+            format!("Solver {} does not support quadratic models.", self.name()),
+        ))
+    }
+}
+
+// the variables encoded with VariableEncoding::BinaryExpansion are handed to the backend
+// as binaries, and the solution is decoded to the columns of the model
+pub(crate) fn solve_encoded<F>(
+    model: &LinearMetaModel,
+    solve: F,
+) -> Result<SolverOutput, SolverError>
+where
+    F: FnOnce(&LinearMetaModel) -> Result<SolverOutput, SolverError>,
+{
+    if !model.has_binary_expansion() {
+        return solve(model);
+    }
+    let expansion = BinaryExpansion::new(model)?;
+    let mut output = solve(&expansion.model)?;
+    if !output.solution.is_empty() {
+        output.solution = expansion.decode(&output.solution);
+    }
+    Ok(output)
+}
+
+// quadratic terms cannot be expanded
+pub(crate) fn check_quadratic_encoding(model: &QuadraticMetaModel) -> Result<(), SolverError> {
+    if model.linear().has_binary_expansion() {
+        return Err(SolverError::new(
+            ErrorCode::OREngineModelingException,
+            format!(
+                "Binary expansion of variables is not supported in quadratic model {}.",
+                model.name()
+            ),
+        ));
+    }
+    Ok(())
+}
+
+#[test]
+fn test_solve_encoded() {
+    use crate::core::backend::output::SolverStatus;
+    use crate::core::frontend::model::VariableEncoding;
+    use crate::core::frontend::variable::*;
+
+    let x = TerVar::new_with_name("x");
+    let y = BinVar::new_with_name("y");
+    let mut model = LinearMetaModel::new();
+    model
+        .add_var_with_encoding(&x, VariableEncoding::BinaryExpansion)
+        .unwrap();
+    model.add_var(&y).unwrap();
+
+    let output = solve_encoded(&model, |model| {
+        // x_b0, x_b1 and y
+        assert_eq!(model.variables().len(), 3);
+        let mut output = SolverOutput::new(SolverStatus::Optimal);
+        output.solution = vec![0., 1., 1.];
+        Ok(output)
+    })
+    .unwrap();
+    assert_eq!(output.solution, vec![2., 1.]);
+}
@@ -0,0 +1,87 @@
+use super::config::SolverConfig;
+use super::error::SolverError;
+use super::output::SolverOutput;
+use super::solver::Solver;
+#[allow(unused_imports)]
+use super::solvers::*;
+use crate::core::frontend::model::{LinearMetaModel, QuadraticMetaModel};
+use ospf_rust_base::ErrorCode;
+use std::collections::HashMap;
+
+pub type SolverFactory = fn() -> Result<Box<dyn Solver>, SolverError>;
+
+pub struct SolverRegistry {
+    _factories: HashMap<String, SolverFactory>,
+}
+
+impl SolverRegistry {
+    pub fn new() -> Self {
+        #[allow(unused_mut)]
+        let mut ret = Self::new_empty();
+        #[cfg(feature = "glpk")]
+        ret.register("glpk", || Ok(Box::new(GlpkSolver::new()?)));
+        #[cfg(feature = "scip")]
+        ret.register("scip", || Ok(Box::new(ScipSolver::new()?)));
+        #[cfg(feature = "gurobi")]
+        ret.register("gurobi", || Ok(Box::new(GurobiSolver::new()?)));
+        ret
+    }
+
+    pub fn new_empty() -> Self {
+        Self {
+            _factories: HashMap::new(),
+        }
+    }
+
+    pub fn register(&mut self, name: &str, factory: SolverFactory) {
+        self._factories.insert(name.to_lowercase(), factory);
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self._factories.contains_key(&name.to_lowercase())
+    }
+
+    pub fn names(&self) -> Vec<&str> {
+        let mut ret: Vec<&str> = self._factories.keys().map(|name| name.as_str()).collect();
+        ret.sort();
+        ret
+    }
+
+    pub fn get(&self, name: &str) -> Result<Box<dyn Solver>, SolverError> {
+        match self._factories.get(&name.to_lowercase()) {
+            Option::Some(factory) => factory(),
+            Option::None => Err(SolverError::new(
+                ErrorCode::SolverNotFound,
+                format!(
+                    "Solver \"{}\" is not available, available solvers: [{}].",
+                    name,
+                    self.names().join(", ")
+                ),
+            )),
+        }
+    }
+
+    pub fn solve(
+        &self,
+        name: &str,
+        model: &LinearMetaModel,
+        config: &SolverConfig,
+    ) -> Result<SolverOutput, SolverError> {
+        self.get(name)?.solve(model, config)
+    }
+
+    pub fn solve_quadratic(
+        &self,
+        name: &str,
+        model: &QuadraticMetaModel,
+        config: &SolverConfig,
+    ) -> Result<SolverOutput, SolverError> {
+        self.get(name)?.solve_quadratic(model, config)
+    }
+}
+
+impl Default for SolverRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
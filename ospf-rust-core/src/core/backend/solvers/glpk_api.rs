@@ -0,0 +1,40 @@
+pub use super::glpk::*;
+#[cfg(feature = "dynamic")]
+use std::os::raw::*;
+
+solver_api! {
+    "GLPK", "GLPK_LIBRARY", ["glpk"];
+    fn glp_create_prob() -> *mut glp_prob;
+    fn glp_delete_prob(P: *mut glp_prob);
+    fn glp_erase_prob(P: *mut glp_prob);
+    fn glp_set_prob_name(P: *mut glp_prob, name: *const c_char);
+    fn glp_set_obj_dir(P: *mut glp_prob, dir: c_int);
+    fn glp_add_rows(P: *mut glp_prob, nrs: c_int) -> c_int;
+    fn glp_add_cols(P: *mut glp_prob, ncs: c_int) -> c_int;
+    fn glp_set_row_name(P: *mut glp_prob, i: c_int, name: *const c_char);
+    fn glp_set_col_name(P: *mut glp_prob, j: c_int, name: *const c_char);
+    fn glp_set_row_bnds(P: *mut glp_prob, i: c_int, type_: c_int, lb: f64, ub: f64);
+    fn glp_set_col_bnds(P: *mut glp_prob, j: c_int, type_: c_int, lb: f64, ub: f64);
+    fn glp_set_obj_coef(P: *mut glp_prob, j: c_int, coef: f64);
+    fn glp_set_mat_row(P: *mut glp_prob, i: c_int, len: c_int, ind: *const c_int, val: *const f64);
+    fn glp_get_num_cols(P: *mut glp_prob) -> c_int;
+    fn glp_set_col_kind(P: *mut glp_prob, j: c_int, kind: c_int);
+    fn glp_init_smcp(parm: *mut glp_smcp);
+    fn glp_simplex(P: *mut glp_prob, parm: *const glp_smcp) -> c_int;
+    fn glp_get_status(P: *mut glp_prob) -> c_int;
+    fn glp_get_obj_val(P: *mut glp_prob) -> f64;
+    fn glp_get_col_prim(P: *mut glp_prob, j: c_int) -> f64;
+    fn glp_init_iocp(parm: *mut glp_iocp);
+    fn glp_intopt(P: *mut glp_prob, parm: *const glp_iocp) -> c_int;
+    fn glp_mip_status(P: *mut glp_prob) -> c_int;
+    fn glp_mip_obj_val(P: *mut glp_prob) -> f64;
+    fn glp_mip_col_val(P: *mut glp_prob, j: c_int) -> f64;
+    fn glp_get_prim_stat(P: *mut glp_prob) -> c_int;
+    fn glp_ios_tree_size(T: *mut glp_tree, a_cnt: *mut c_int, n_cnt: *mut c_int, t_cnt: *mut c_int);
+    fn glp_ios_best_node(T: *mut glp_tree) -> c_int;
+    fn glp_ios_node_bound(T: *mut glp_tree, p: c_int) -> f64;
+    fn glp_ios_get_prob(T: *mut glp_tree) -> *mut glp_prob;
+    fn glp_ios_terminate(T: *mut glp_tree);
+    fn glp_open_tee(name: *const c_char) -> c_int;
+    fn glp_close_tee() -> c_int;
+}
@@ -0,0 +1,780 @@
+use super::super::config::{SolverConfig, Verbosity};
+use super::super::error::SolverError;
+use super::super::output::*;
+use super::super::solver::{solve_encoded, Solver};
+use super::glpk_api::*;
+use crate::core::frontend::constraint::{IndicatorConstraint, SosCategory, SpecialOrderedSet};
+use crate::core::frontend::expression::VariableSymbol;
+use crate::core::frontend::model::*;
+use crate::core::frontend::variable::VariableCategory;
+use ospf_rust_base::ErrorCode;
+use std::ffi::CString;
+use std::os::raw::{c_int, c_void};
+use std::ptr;
+use std::time::Instant;
+
+pub struct GlpkSolver {
+    prob: *mut glp_prob,
+    integer: bool,
+    // columns of the model variables, auxiliary columns come after them
+    variable_amount: c_int,
+    pub simplex_parameter: glp_smcp,
+    pub intopt_parameter: glp_iocp,
+    absolute_gap: Option<f64>,
+    log_file: Option<CString>,
+}
+
+fn bound_type(lb: f64, ub: f64) -> c_int {
+    (match (lb.is_finite(), ub.is_finite()) {
+        (false, false) => GLP_FR,
+        (true, false) => GLP_LO,
+        (false, true) => GLP_UP,
+        (true, true) => {
+            if lb == ub {
+                GLP_FX
+            } else {
+                GLP_DB
+            }
+        }
+    }) as c_int
+}
+
+fn column_kind(category: VariableCategory) -> c_int {
+    (match category {
+        VariableCategory::Binary => GLP_BV,
+        VariableCategory::Integer | VariableCategory::SemiInteger => GLP_IV,
+        VariableCategory::Continuous | VariableCategory::SemiContinuous => GLP_CV,
+    }) as c_int
+}
+
+// a semi variable is 0 or in [lb, ub], so its column takes the hull of both
+fn column_bounds(variable: &ModelVariable) -> (f64, f64) {
+    if variable.category.is_semi() {
+        (variable.lower_bound.min(0.), variable.upper_bound.max(0.))
+    } else {
+        (variable.lower_bound, variable.upper_bound)
+    }
+}
+
+fn status_of(status: c_int) -> SolverStatus {
+    match status as u32 {
+        GLP_OPT => SolverStatus::Optimal,
+        GLP_FEAS => SolverStatus::Feasible,
+        GLP_NOFEAS => SolverStatus::Infeasible,
+        GLP_UNBND => SolverStatus::Unbounded,
+        _ => SolverStatus::Error,
+    }
+}
+
+// GLPK only reports the search tree statistics inside of the branch-and-cut callback
+struct SearchInfo {
+    nodes: u64,
+    best_bound: f64,
+    absolute_gap: Option<f64>,
+    gap_reached: bool,
+}
+
+unsafe extern "C" fn record_search_info(tree: *mut glp_tree, info: *mut c_void) {
+    let info = &mut *(info as *mut SearchInfo);
+    let mut total_amount: c_int = 0;
+    glp_ios_tree_size(tree, ptr::null_mut(), ptr::null_mut(), &mut total_amount);
+    info.nodes = total_amount as u64;
+    let best_node = glp_ios_best_node(tree);
+    if best_node != 0 {
+        info.best_bound = glp_ios_node_bound(tree, best_node);
+    }
+
+    // GLPK has no absolute gap tolerance, the search is stopped here instead
+    if let Some(absolute_gap) = info.absolute_gap {
+        let prob = glp_ios_get_prob(tree);
+        if best_node != 0
+            && glp_mip_status(prob) as u32 == GLP_FEAS
+            && (glp_mip_obj_val(prob) - info.best_bound).abs() <= absolute_gap
+        {
+            info.gap_reached = true;
+            glp_ios_terminate(tree);
+        }
+    }
+}
+
+fn message_level(verbosity: Verbosity) -> c_int {
+    (match verbosity {
+        Verbosity::Quiet => GLP_MSG_OFF,
+        Verbosity::Error => GLP_MSG_ERR,
+        Verbosity::Normal => GLP_MSG_ON,
+        Verbosity::Detailed => GLP_MSG_ALL,
+    }) as c_int
+}
+
+// glp_intopt needs an optimal basis unless its presolver is on
+fn default_parameters() -> (glp_smcp, glp_iocp) {
+    unsafe {
+        let mut simplex_parameter = std::mem::zeroed::<glp_smcp>();
+        glp_init_smcp(&mut simplex_parameter);
+        let mut intopt_parameter = std::mem::zeroed::<glp_iocp>();
+        glp_init_iocp(&mut intopt_parameter);
+        intopt_parameter.presolve = GLP_ON as c_int;
+        (simplex_parameter, intopt_parameter)
+    }
+}
+
+fn check_range(variable: &ModelVariable) -> Result<(), SolverError> {
+    if variable.empty() {
+        Err(SolverError::new(
+            ErrorCode::ORModelNoSolution,
+            format!(
+                "Variable {} has an empty range [{}, {}].",
+                variable.symbol, variable.lower_bound, variable.upper_bound
+            ),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+fn column_of(model: &LinearMetaModel, symbol: &VariableSymbol) -> Result<usize, SolverError> {
+    model.column_of(symbol).ok_or_else(|| {
+        SolverError::new(
+            ErrorCode::OREngineModelingException,
+            format!("Variable {} is not registered in the model.", symbol),
+        )
+    })
+}
+
+fn c_name(name: &str) -> Result<CString, SolverError> {
+    CString::new(name).map_err(|_| {
+        SolverError::new(
+            ErrorCode::OREngineModelingException,
+            format!("Invalid name \"{}\" for GLPK.", name),
+        )
+    })
+}
+
+impl GlpkSolver {
+    pub fn new() -> Result<Self, SolverError> {
+        load()?;
+        let (simplex_parameter, intopt_parameter) = default_parameters();
+        Ok(Self {
+            prob: unsafe { glp_create_prob() },
+            integer: false,
+            variable_amount: 0,
+            simplex_parameter: simplex_parameter,
+            intopt_parameter: intopt_parameter,
+            absolute_gap: None,
+            log_file: None,
+        })
+    }
+
+    // GLPK runs single-threaded and has no random seed, threads and seed are ignored;
+    // parameters are reset first, so that no option of a previous config stays
+    pub fn configure(&mut self, config: &SolverConfig) -> Result<(), SolverError> {
+        let (simplex_parameter, intopt_parameter) = default_parameters();
+        self.simplex_parameter = simplex_parameter;
+        self.intopt_parameter = intopt_parameter;
+        self.absolute_gap = config.absolute_gap;
+        self.log_file = None;
+        if let Some(time_limit) = config.time_limit_millis() {
+            self.simplex_parameter.tm_lim = time_limit;
+            self.intopt_parameter.tm_lim = time_limit;
+        }
+        if let Some(gap) = config.relative_gap {
+            self.intopt_parameter.mip_gap = gap;
+        }
+        if let Some(verbosity) = config.verbosity {
+            self.simplex_parameter.msg_lev = message_level(verbosity);
+            self.intopt_parameter.msg_lev = message_level(verbosity);
+        }
+        if let Some(log_file) = &config.log_file {
+            self.log_file = Some(c_name(&log_file.to_string_lossy())?);
+        }
+        Ok(())
+    }
+
+    pub fn load(&mut self, model: &LinearMetaModel) -> Result<(), SolverError> {
+        unsafe {
+            glp_erase_prob(self.prob);
+            if !model.name.is_empty() {
+                glp_set_prob_name(self.prob, c_name(&model.name)?.as_ptr());
+            }
+
+            let variables = model.variables();
+            if !variables.is_empty() {
+                glp_add_cols(self.prob, variables.len() as c_int);
+            }
+            for (i, variable) in variables.iter().enumerate() {
+                check_range(variable)?;
+                let j = (i + 1) as c_int;
+                glp_set_col_name(self.prob, j, c_name(model.name_of(i))?.as_ptr());
+                glp_set_col_kind(self.prob, j, column_kind(variable.category));
+                let (lb, ub) = column_bounds(variable);
+                glp_set_col_bnds(self.prob, j, bound_type(lb, ub), lb, ub);
+            }
+            self.variable_amount = variables.len() as c_int;
+
+            let constraints = model.constraints();
+            if !constraints.is_empty() {
+                glp_add_rows(self.prob, constraints.len() as c_int);
+            }
+            for (i, constraint) in constraints.iter().enumerate() {
+                let row = (i + 1) as c_int;
+                if !constraint.name.is_empty() {
+                    glp_set_row_name(self.prob, row, c_name(&constraint.name)?.as_ptr());
+                }
+                let (lb, ub) = (constraint.lower_bound(), constraint.upper_bound());
+                glp_set_row_bnds(self.prob, row, bound_type(lb, ub), lb, ub);
+
+                let expression = constraint.expression();
+                let mut indexes: Vec<c_int> = vec![0];
+                let mut values: Vec<f64> = vec![0.];
+                for monomial in expression.monomials() {
+                    indexes.push((column_of(model, &monomial.symbol)? + 1) as c_int);
+                    values.push(monomial.coefficient);
+                }
+                glp_set_mat_row(
+                    self.prob,
+                    row,
+                    (indexes.len() - 1) as c_int,
+                    indexes.as_ptr(),
+                    values.as_ptr(),
+                );
+            }
+
+            self.add_reformulation(&Reformulation::of(model)?)?;
+
+            glp_set_obj_dir(
+                self.prob,
+                (match model.object_category() {
+                    ObjectCategory::Minimum => GLP_MIN,
+                    ObjectCategory::Maximum => GLP_MAX,
+                }) as c_int,
+            );
+            let objective = model.objective();
+            glp_set_obj_coef(self.prob, 0, objective.constant());
+            for monomial in objective.monomials() {
+                let column = column_of(model, &monomial.symbol)?;
+                glp_set_obj_coef(self.prob, (column + 1) as c_int, monomial.coefficient);
+            }
+        }
+        self.integer = model.is_integer();
+        Ok(())
+    }
+
+    unsafe fn add_reformulation(
+        &mut self,
+        reformulation: &Reformulation,
+    ) -> Result<(), SolverError> {
+        if !reformulation.binaries.is_empty() {
+            glp_add_cols(self.prob, reformulation.binaries.len() as c_int);
+        }
+        for (k, name) in reformulation.binaries.iter().enumerate() {
+            let column = reformulation.first_column + k as c_int;
+            glp_set_col_name(self.prob, column, c_name(name)?.as_ptr());
+            glp_set_col_kind(self.prob, column, GLP_BV as c_int);
+        }
+        for (terms, lb, ub) in reformulation.rows.iter() {
+            let row = glp_add_rows(self.prob, 1);
+            glp_set_row_bnds(self.prob, row, bound_type(*lb, *ub), *lb, *ub);
+            let mut indexes: Vec<c_int> = vec![0];
+            let mut values: Vec<f64> = vec![0.];
+            for (column, value) in terms {
+                indexes.push(*column);
+                values.push(*value);
+            }
+            glp_set_mat_row(
+                self.prob,
+                row,
+                terms.len() as c_int,
+                indexes.as_ptr(),
+                values.as_ptr(),
+            );
+        }
+        Ok(())
+    }
+
+    pub fn solve(&mut self) -> Result<SolverOutput, SolverError> {
+        if let Some(log_file) = &self.log_file {
+            if unsafe { glp_open_tee(log_file.as_ptr()) } != 0 {
+                return Err(SolverError::new(
+                    ErrorCode::OREngineSolvingException,
+                    format!("Cannot open log file {:?} for GLPK.", log_file),
+                ));
+            }
+        }
+        let ret = if self.integer {
+            self.intopt()
+        } else {
+            self.simplex()
+        };
+        if self.log_file.is_some() {
+            unsafe {
+                glp_close_tee();
+            }
+        }
+        ret
+    }
+
+    fn simplex(&mut self) -> Result<SolverOutput, SolverError> {
+        let begin = Instant::now();
+        let ret = unsafe { glp_simplex(self.prob, &self.simplex_parameter) } as u32;
+        let time = begin.elapsed();
+        let status = match ret {
+            0 => status_of(unsafe { glp_get_status(self.prob) }),
+            GLP_ETMLIM => SolverStatus::TimeLimit,
+            GLP_EITLIM | GLP_ESTOP => SolverStatus::Interrupted,
+            GLP_ENOPFS => SolverStatus::Infeasible,
+            GLP_ENODFS => SolverStatus::InfeasibleOrUnbounded,
+            _ => {
+                return Err(SolverError::new(
+                    ErrorCode::OREngineSolvingException,
+                    format!("glp_simplex failed with code {}.", ret),
+                ))
+            }
+        };
+
+        let mut output = SolverOutput::new(status);
+        output.time = time;
+        if status != SolverStatus::Unbounded
+            && unsafe { glp_get_prim_stat(self.prob) } as u32 == GLP_FEAS
+        {
+            output.objective = unsafe { glp_get_obj_val(self.prob) };
+            if status == SolverStatus::Optimal {
+                output.best_bound = output.objective;
+                output.gap = 0.;
+            }
+            output.solution = (1..=self.column_amount())
+                .map(|j| unsafe { glp_get_col_prim(self.prob, j) })
+                .collect();
+        }
+        Ok(output)
+    }
+
+    fn intopt(&mut self) -> Result<SolverOutput, SolverError> {
+        let mut info = SearchInfo {
+            nodes: 0,
+            best_bound: f64::NAN,
+            absolute_gap: self.absolute_gap,
+            gap_reached: false,
+        };
+        let mut parameter = self.intopt_parameter;
+        if parameter.cb_func.is_none() {
+            parameter.cb_func = Some(record_search_info);
+            parameter.cb_info = &mut info as *mut SearchInfo as *mut c_void;
+        }
+        let begin = Instant::now();
+        let ret = unsafe { glp_intopt(self.prob, &parameter) } as u32;
+        let time = begin.elapsed();
+        let mip_status = status_of(unsafe { glp_mip_status(self.prob) });
+        let status = match ret {
+            0 => mip_status,
+            GLP_EMIPGAP => SolverStatus::Feasible,
+            GLP_ETMLIM => SolverStatus::TimeLimit,
+            GLP_ESTOP if info.gap_reached => SolverStatus::Feasible,
+            GLP_ESTOP => SolverStatus::Interrupted,
+            GLP_ENOPFS => SolverStatus::Infeasible,
+            GLP_ENODFS => SolverStatus::InfeasibleOrUnbounded,
+            _ => {
+                return Err(SolverError::new(
+                    ErrorCode::OREngineSolvingException,
+                    format!("glp_intopt failed with code {}.", ret),
+                ))
+            }
+        };
+
+        let mut output = SolverOutput::new(status);
+        output.time = time;
+        output.nodes = info.nodes;
+        if mip_status == SolverStatus::Optimal || mip_status == SolverStatus::Feasible {
+            output.objective = unsafe { glp_mip_obj_val(self.prob) };
+            output.best_bound = if status == SolverStatus::Optimal {
+                output.objective
+            } else {
+                info.best_bound
+            };
+            output.gap = relative_gap(output.objective, output.best_bound);
+            output.solution = (1..=self.column_amount())
+                .map(|j| unsafe { glp_mip_col_val(self.prob, j) })
+                .collect();
+        }
+        Ok(output)
+    }
+
+    fn column_amount(&self) -> c_int {
+        self.variable_amount
+    }
+}
+
+// terms, lower bound and upper bound
+type Row = (Vec<(c_int, f64)>, f64, f64);
+
+// auxiliary binaries and rows of the reformulations of what GLPK has no native support for,
+// the binaries taking the columns after the ones of the model variables
+struct Reformulation {
+    first_column: c_int,
+    binaries: Vec<String>,
+    rows: Vec<Row>,
+}
+
+impl Reformulation {
+    fn of(model: &LinearMetaModel) -> Result<Self, SolverError> {
+        let mut ret = Self {
+            first_column: (model.variables().len() + 1) as c_int,
+            binaries: Vec::new(),
+            rows: Vec::new(),
+        };
+        for (i, variable) in model.variables().iter().enumerate() {
+            if variable.category.is_semi() {
+                ret.linearize_semi_variable((i + 1) as c_int, variable)?;
+            }
+        }
+        for (i, set) in model.special_ordered_sets().iter().enumerate() {
+            ret.reformulate_special_ordered_set(model, i, set)?;
+        }
+        for (i, constraint) in model.indicator_constraints().iter().enumerate() {
+            ret.reformulate_indicator_constraint(model, i, constraint)?;
+        }
+        Ok(ret)
+    }
+
+    // lb * y <= x <= ub * y with an auxiliary binary y,
+    // unneeded if 0 is already in [lb, ub]
+    fn linearize_semi_variable(
+        &mut self,
+        column: c_int,
+        variable: &ModelVariable,
+    ) -> Result<(), SolverError> {
+        let (lb, ub) = (variable.lower_bound, variable.upper_bound);
+        if lb <= 0. && 0. <= ub {
+            return Ok(());
+        }
+        if !lb.is_finite() || !ub.is_finite() {
+            return Err(SolverError::new(
+                ErrorCode::OREngineModelingException,
+                format!(
+                    "Semi variable {} needs a finite range to be linearized, not [{}, {}].",
+                    variable.symbol, lb, ub
+                ),
+            ));
+        }
+        let indicator = self.add_binary_column(&format!("{}_nonzero", variable.symbol));
+        self.add_row(&[(column, 1.), (indicator, -ub)], f64::NEG_INFINITY, 0.);
+        self.add_row(&[(column, 1.), (indicator, -lb)], 0., f64::INFINITY);
+        Ok(())
+    }
+
+    // SOS1: x_i is bounded by its range times y_i, with sum of y_i <= 1
+    // SOS2: with the members ordered by weight, x_i is bounded by its range times
+    // z_(i-1) + z_i of the segments next to it, with sum of z_k <= 1
+    fn reformulate_special_ordered_set(
+        &mut self,
+        model: &LinearMetaModel,
+        index: usize,
+        set: &SpecialOrderedSet,
+    ) -> Result<(), SolverError> {
+        let members = set.ordered_symbols();
+        let amount = match set.category {
+            SosCategory::Sos1 => members.len(),
+            SosCategory::Sos2 => members.len().saturating_sub(1),
+        };
+        if amount <= 1 {
+            return Ok(());
+        }
+        let prefix = if set.name.is_empty() {
+            format!("sos{}", index)
+        } else {
+            set.name.clone()
+        };
+        let mut switches: Vec<c_int> = Vec::new();
+        for k in 0..amount {
+            switches.push(self.add_binary_column(&format!("{}_{}", prefix, k)));
+        }
+        let terms: Vec<(c_int, f64)> = switches.iter().map(|switch| (*switch, 1.)).collect();
+        self.add_row(&terms, f64::NEG_INFINITY, 1.);
+
+        for (i, symbol) in members.into_iter().enumerate() {
+            let column = column_of(model, symbol)?;
+            let variable = &model.variables()[column];
+            let (lb, ub) = (variable.lower_bound, variable.upper_bound);
+            if !lb.is_finite() || !ub.is_finite() {
+                return Err(SolverError::new(
+                    ErrorCode::OREngineModelingException,
+                    format!(
+                        "Member {} of {} needs a finite range to be reformulated, not [{}, {}].",
+                        symbol, prefix, lb, ub
+                    ),
+                ));
+            }
+            let neighbours: Vec<c_int> = match set.category {
+                SosCategory::Sos1 => vec![switches[i]],
+                SosCategory::Sos2 => switches[i.saturating_sub(1)..(i + 1).min(amount)].to_vec(),
+            };
+            let column = (column + 1) as c_int;
+            let mut terms = vec![(column, 1.)];
+            terms.extend(neighbours.iter().map(|switch| (*switch, -ub)));
+            self.add_row(&terms, f64::NEG_INFINITY, 0.);
+            if lb < 0. {
+                let mut terms = vec![(column, 1.)];
+                terms.extend(neighbours.iter().map(|switch| (*switch, -lb)));
+                self.add_row(&terms, 0., f64::INFINITY);
+            }
+        }
+        Ok(())
+    }
+
+    // a^T x <= ub + M * (1 - y) with M = max(a^T x) - ub from the variable bounds,
+    // and a^T x >= lb - M * (1 - y) likewise, y being z or 1 - z by the active value
+    fn reformulate_indicator_constraint(
+        &mut self,
+        model: &LinearMetaModel,
+        index: usize,
+        constraint: &IndicatorConstraint,
+    ) -> Result<(), SolverError> {
+        let name = if constraint.name.is_empty() {
+            format!("indicator{}", index)
+        } else {
+            constraint.name.clone()
+        };
+        let indicator = (column_of(model, &constraint.indicator)? + 1) as c_int;
+        let expression = constraint.constraint.expression();
+        let mut terms: Vec<(c_int, f64)> = Vec::new();
+        for monomial in expression.monomials() {
+            let column = (column_of(model, &monomial.symbol)? + 1) as c_int;
+            terms.push((column, monomial.coefficient));
+        }
+        let (min, max) = model.bounds_of(&expression);
+        let (lb, ub) = (
+            constraint.constraint.lower_bound(),
+            constraint.constraint.upper_bound(),
+        );
+
+        // (big-M, whether it is the upper side) of the sides not implied by the bounds
+        let mut sides = Vec::new();
+        if ub < max {
+            sides.push((max - ub, true));
+        }
+        if min < lb {
+            sides.push((lb - min, false));
+        }
+        for (big_m, upper) in sides {
+            if !big_m.is_finite() {
+                return Err(SolverError::new(
+                    ErrorCode::OREngineModelingException,
+                    format!(
+                        "{} needs finite variable bounds to derive its big-M, the expression is in [{}, {}].",
+                        name, min, max
+                    ),
+                ));
+            }
+            let (coefficient, offset) = match (upper, constraint.active_value) {
+                (true, true) => (big_m, big_m),
+                (true, false) => (-big_m, 0.),
+                (false, true) => (-big_m, -big_m),
+                (false, false) => (big_m, 0.),
+            };
+            let mut terms = terms.clone();
+            match terms.iter_mut().find(|(column, _)| *column == indicator) {
+                Option::Some(term) => term.1 += coefficient,
+                Option::None => terms.push((indicator, coefficient)),
+            }
+            if upper {
+                self.add_row(&terms, f64::NEG_INFINITY, ub + offset);
+            } else {
+                self.add_row(&terms, lb + offset, f64::INFINITY);
+            }
+        }
+        Ok(())
+    }
+
+    fn add_binary_column(&mut self, name: &str) -> c_int {
+        self.binaries.push(String::from(name));
+        self.first_column + (self.binaries.len() - 1) as c_int
+    }
+
+    fn add_row(&mut self, terms: &[(c_int, f64)], lb: f64, ub: f64) {
+        self.rows.push((terms.to_vec(), lb, ub));
+    }
+}
+
+impl Solver for GlpkSolver {
+    fn name(&self) -> &'static str {
+        "glpk"
+    }
+
+    fn solve(
+        &mut self,
+        model: &LinearMetaModel,
+        config: &SolverConfig,
+    ) -> Result<SolverOutput, SolverError> {
+        solve_encoded(model, |model| {
+            self.load(model)?;
+            self.configure(config)?;
+            GlpkSolver::solve(self)?.into_result()
+        })
+    }
+}
+
+impl Drop for GlpkSolver {
+    fn drop(&mut self) {
+        unsafe {
+            glp_delete_prob(self.prob);
+        }
+    }
+}
+
+#[test]
+fn test_status_of() {
+    assert_eq!(status_of(GLP_OPT as c_int), SolverStatus::Optimal);
+    assert_eq!(status_of(GLP_FEAS as c_int), SolverStatus::Feasible);
+    assert_eq!(status_of(GLP_NOFEAS as c_int), SolverStatus::Infeasible);
+    assert_eq!(status_of(GLP_UNBND as c_int), SolverStatus::Unbounded);
+    assert_eq!(status_of(GLP_UNDEF as c_int), SolverStatus::Error);
+
+    assert_eq!(
+        bound_type(f64::NEG_INFINITY, f64::INFINITY),
+        GLP_FR as c_int
+    );
+    assert_eq!(bound_type(0., f64::INFINITY), GLP_LO as c_int);
+    assert_eq!(bound_type(f64::NEG_INFINITY, 0.), GLP_UP as c_int);
+    assert_eq!(bound_type(1., 1.), GLP_FX as c_int);
+    assert_eq!(bound_type(0., 1.), GLP_DB as c_int);
+}
+
+#[test]
+fn test_semi_variable_reformulation() {
+    use crate::core::frontend::variable::*;
+
+    let x = RealVar::new_with_name("x");
+    let mut s = SemiRealVar::new_with_name("s");
+    s.range.set_lb(2.).unwrap();
+    s.range.set_ub(5.).unwrap();
+    let mut t = SemiIntVar::new_with_name("t");
+    t.range.set_ub(3).unwrap();
+    let mut model = LinearMetaModel::new();
+    model.add_var(&x).unwrap();
+    model.add_var(&s).unwrap();
+    model.add_var(&t).unwrap();
+
+    // t can be 0 within its range, only s gets an auxiliary binary
+    let reformulation = Reformulation::of(&model).unwrap();
+    assert_eq!(reformulation.first_column, 4);
+    assert_eq!(reformulation.binaries, vec![String::from("s_nonzero")]);
+    let column = (model.column_of(&VariableSymbol::from(&s)).unwrap() + 1) as c_int;
+    let variable = &model.variables()[(column - 1) as usize];
+    assert_eq!(column_bounds(variable), (0., 5.));
+    assert_eq!(
+        reformulation.rows,
+        vec![
+            (
+                vec![(column, 1.), (4, -variable.upper_bound)],
+                f64::NEG_INFINITY,
+                0.
+            ),
+            (
+                vec![(column, 1.), (4, -variable.lower_bound)],
+                0.,
+                f64::INFINITY
+            ),
+        ]
+    );
+
+    let mut u = SemiRealVar::new_with_name("u");
+    u.range.set_lb(1.).unwrap();
+    model.add_var(&u).unwrap();
+    assert!(Reformulation::of(&model).is_err());
+}
+
+#[test]
+fn test_special_ordered_set_reformulation() {
+    use crate::core::frontend::variable::*;
+
+    let mut x1 = RealVar::new_with_name("x1");
+    x1.range.set_lb(0.).unwrap();
+    x1.range.set_ub(2.).unwrap();
+    let mut x2 = RealVar::new_with_name("x2");
+    x2.range.set_lb(-1.).unwrap();
+    x2.range.set_ub(1.).unwrap();
+    let mut x3 = RealVar::new_with_name("x3");
+    x3.range.set_lb(0.).unwrap();
+    x3.range.set_ub(3.).unwrap();
+    let mut model = LinearMetaModel::new();
+    model.add_vars([&x1, &x2, &x3]).unwrap();
+    model
+        .add_special_ordered_set(
+            SpecialOrderedSet::new_sos2([&x1, &x2, &x3])
+                .with_weights(vec![3., 1., 2.])
+                .unwrap(),
+        )
+        .unwrap();
+
+    // ordered by weight as x2, x3, x1, with a binary for each of the 2 segments
+    let reformulation = Reformulation::of(&model).unwrap();
+    assert_eq!(
+        reformulation.binaries,
+        vec![String::from("sos0_0"), String::from("sos0_1")]
+    );
+    let (z0, z1) = (4, 5);
+    let columns: Vec<c_int> = model.special_ordered_sets()[0]
+        .ordered_symbols()
+        .into_iter()
+        .map(|symbol| (model.column_of(symbol).unwrap() + 1) as c_int)
+        .collect();
+    assert_eq!(columns, vec![2, 3, 1]);
+    assert_eq!(
+        reformulation.rows,
+        vec![
+            (vec![(z0, 1.), (z1, 1.)], f64::NEG_INFINITY, 1.),
+            (vec![(2, 1.), (z0, -1.)], f64::NEG_INFINITY, 0.),
+            (vec![(2, 1.), (z0, 1.)], 0., f64::INFINITY),
+            (vec![(3, 1.), (z0, -3.), (z1, -3.)], f64::NEG_INFINITY, 0.),
+            (vec![(1, 1.), (z1, -2.)], f64::NEG_INFINITY, 0.),
+        ]
+    );
+}
+
+#[test]
+fn test_indicator_constraint_reformulation() {
+    use crate::core::frontend::constraint::*;
+    use crate::core::frontend::variable::*;
+
+    let mut x = RealVar::new_with_name("x");
+    x.range.set_lb(0.).unwrap();
+    x.range.set_ub(10.).unwrap();
+    let mut y = RealVar::new_with_name("y");
+    y.range.set_lb(-2.).unwrap();
+    y.range.set_ub(4.).unwrap();
+    let b = BinVar::new_with_name("b");
+    let mut model = LinearMetaModel::new();
+    model.add_vars([&x, &y]).unwrap();
+    model.add_var(&b).unwrap();
+    model
+        .add_indicator_constraint(IndicatorConstraint::new(&b, (&x + &y).leq(5.)))
+        .unwrap();
+    model
+        .add_indicator_constraint(IndicatorConstraint::new_negated(&b, (&x - &y).geq(1.)))
+        .unwrap();
+
+    // if b = 1 then x + y <= 5, with M = max(x + y) - 5
+    // if b = 0 then x - y >= 1, with M = 1 - min(x - y)
+    let reformulation = Reformulation::of(&model).unwrap();
+    assert!(reformulation.binaries.is_empty());
+    let (_, max) = model.bounds_of(&(&x + &y));
+    let (min, _) = model.bounds_of(&(&x - &y));
+    assert_eq!((max, min), (14., -4.));
+    assert_eq!(
+        reformulation.rows,
+        vec![
+            (
+                vec![(1, 1.), (2, 1.), (3, max - 5.)],
+                f64::NEG_INFINITY,
+                5. + (max - 5.)
+            ),
+            (vec![(1, 1.), (2, -1.), (3, 1. - min)], 1., f64::INFINITY),
+        ]
+    );
+
+    let z = RealVar::new_with_name("z");
+    model.add_var(&z).unwrap();
+    model
+        .add_indicator_constraint(IndicatorConstraint::new(&b, (&x + &z).leq(5.)))
+        .unwrap();
+    assert!(Reformulation::of(&model).is_err());
+}
@@ -0,0 +1,109 @@
+pub use super::gurobi::*;
+#[cfg(feature = "dynamic")]
+use std::os::raw::*;
+
+solver_api! {
+    "Gurobi", "GUROBI_LIBRARY", ["gurobi81", "gurobi"];
+    fn GRBloadenv(envP: *mut *mut GRBenv, logfilename: *const c_char) -> c_int;
+    fn GRBfreeenv(env: *mut GRBenv);
+    fn GRBgeterrormsg(env: *mut GRBenv) -> *const c_char;
+    fn GRBnewmodel(
+        env: *mut GRBenv,
+        modelP: *mut *mut GRBmodel,
+        Pname: *const c_char,
+        numvars: c_int,
+        obj: *mut f64,
+        lb: *mut f64,
+        ub: *mut f64,
+        vtype: *mut c_char,
+        varnames: *mut *mut c_char,
+    ) -> c_int;
+    fn GRBfreemodel(model: *mut GRBmodel) -> c_int;
+    fn GRBupdatemodel(model: *mut GRBmodel) -> c_int;
+    fn GRBaddvar(
+        model: *mut GRBmodel,
+        numnz: c_int,
+        vind: *mut c_int,
+        vval: *mut f64,
+        obj: f64,
+        lb: f64,
+        ub: f64,
+        vtype: c_char,
+        varname: *const c_char,
+    ) -> c_int;
+    fn GRBaddconstr(
+        model: *mut GRBmodel,
+        numnz: c_int,
+        cind: *mut c_int,
+        cval: *mut f64,
+        sense: c_char,
+        rhs: f64,
+        constrname: *const c_char,
+    ) -> c_int;
+    fn GRBaddrangeconstr(
+        model: *mut GRBmodel,
+        numnz: c_int,
+        cind: *mut c_int,
+        cval: *mut f64,
+        lower: f64,
+        upper: f64,
+        constrname: *const c_char,
+    ) -> c_int;
+    fn GRBsetintattr(model: *mut GRBmodel, attrname: *const c_char, newvalue: c_int) -> c_int;
+    fn GRBsetdblattr(model: *mut GRBmodel, attrname: *const c_char, newvalue: f64) -> c_int;
+    fn GRBgetintattr(model: *mut GRBmodel, attrname: *const c_char, valueP: *mut c_int) -> c_int;
+    fn GRBgetdblattr(model: *mut GRBmodel, attrname: *const c_char, valueP: *mut f64) -> c_int;
+    fn GRBgetdblattrarray(
+        model: *mut GRBmodel,
+        attrname: *const c_char,
+        first: c_int,
+        len: c_int,
+        values: *mut f64,
+    ) -> c_int;
+    fn GRBoptimize(model: *mut GRBmodel) -> c_int;
+    fn GRBgetenv(model: *mut GRBmodel) -> *mut GRBenv;
+    fn GRBresetparams(env: *mut GRBenv) -> c_int;
+    fn GRBsetintparam(env: *mut GRBenv, paramname: *const c_char, value: c_int) -> c_int;
+    fn GRBsetdblparam(env: *mut GRBenv, paramname: *const c_char, value: f64) -> c_int;
+    fn GRBsetstrparam(env: *mut GRBenv, paramname: *const c_char, value: *const c_char) -> c_int;
+    fn GRBaddqpterms(
+        model: *mut GRBmodel,
+        numqnz: c_int,
+        qrow: *mut c_int,
+        qcol: *mut c_int,
+        qval: *mut f64,
+    ) -> c_int;
+    fn GRBaddqconstr(
+        model: *mut GRBmodel,
+        numlnz: c_int,
+        lind: *mut c_int,
+        lval: *mut f64,
+        numqnz: c_int,
+        qrow: *mut c_int,
+        qcol: *mut c_int,
+        qval: *mut f64,
+        sense: c_char,
+        rhs: f64,
+        QCname: *const c_char,
+    ) -> c_int;
+    fn GRBaddsos(
+        model: *mut GRBmodel,
+        numsos: c_int,
+        nummembers: c_int,
+        types: *mut c_int,
+        beg: *mut c_int,
+        ind: *mut c_int,
+        weight: *mut f64,
+    ) -> c_int;
+    fn GRBaddgenconstrIndicator(
+        lp: *mut GRBmodel,
+        name: *const c_char,
+        binvar: c_int,
+        binval: c_int,
+        nvars: c_int,
+        vars: *const c_int,
+        vals: *const f64,
+        sense: c_char,
+        rhs: f64,
+    ) -> c_int;
+}
@@ -0,0 +1,674 @@
+use super::super::config::{SolverConfig, Verbosity};
+use super::super::error::SolverError;
+use super::super::output::*;
+use super::super::solver::{check_quadratic_encoding, solve_encoded, Solver};
+use super::gurobi_api::*;
+use crate::core::frontend::constraint::SosCategory;
+use crate::core::frontend::expression::{QuadraticPolynomial, VariableSymbol};
+use crate::core::frontend::model::*;
+use crate::core::frontend::variable::VariableCategory;
+use ospf_rust_base::ErrorCode;
+use std::ffi::{CStr, CString};
+use std::os::raw::{c_char, c_int};
+use std::ptr;
+use std::time::Duration;
+
+pub struct GurobiSolver {
+    env: *mut GRBenv,
+    model: *mut GRBmodel,
+    // columns of the loaded model, ranged rows add slack columns after them
+    columns: usize,
+}
+
+fn variable_type(category: VariableCategory) -> c_char {
+    (match category {
+        VariableCategory::Binary => GRB_BINARY,
+        VariableCategory::Integer => GRB_INTEGER,
+        VariableCategory::Continuous => GRB_CONTINUOUS,
+        VariableCategory::SemiContinuous => GRB_SEMICONT,
+        VariableCategory::SemiInteger => GRB_SEMIINT,
+    }) as c_char
+}
+
+fn status_of(status: c_int) -> SolverStatus {
+    match status as u32 {
+        GRB_OPTIMAL => SolverStatus::Optimal,
+        GRB_SUBOPTIMAL => SolverStatus::Feasible,
+        GRB_INFEASIBLE => SolverStatus::Infeasible,
+        GRB_UNBOUNDED => SolverStatus::Unbounded,
+        GRB_INF_OR_UNBD => SolverStatus::InfeasibleOrUnbounded,
+        GRB_TIME_LIMIT => SolverStatus::TimeLimit,
+        GRB_NODE_LIMIT => SolverStatus::NodeLimit,
+        GRB_INTERRUPTED | GRB_CUTOFF | GRB_ITERATION_LIMIT | GRB_SOLUTION_LIMIT
+        | GRB_USER_OBJ_LIMIT => SolverStatus::Interrupted,
+        _ => SolverStatus::Error,
+    }
+}
+
+fn value_of(value: f64) -> f64 {
+    if value.is_infinite() {
+        value.signum() * GRB_INFINITY
+    } else {
+        value
+    }
+}
+
+fn check_range(variable: &ModelVariable) -> Result<(), SolverError> {
+    if variable.empty() {
+        Err(SolverError::new(
+            ErrorCode::ORModelNoSolution,
+            format!(
+                "Variable {} has an empty range [{}, {}].",
+                variable.symbol, variable.lower_bound, variable.upper_bound
+            ),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+fn column_of(model: &LinearMetaModel, symbol: &VariableSymbol) -> Result<usize, SolverError> {
+    model.column_of(symbol).ok_or_else(|| {
+        SolverError::new(
+            ErrorCode::OREngineModelingException,
+            format!("Variable {} is not registered in the model.", symbol),
+        )
+    })
+}
+
+// row and column indexes of the quadratic terms, each term is given once
+fn quadratic_terms(
+    model: &LinearMetaModel,
+    polynomial: &QuadraticPolynomial,
+) -> Result<(Vec<c_int>, Vec<c_int>, Vec<f64>), SolverError> {
+    let mut rows: Vec<c_int> = Vec::new();
+    let mut cols: Vec<c_int> = Vec::new();
+    let mut values: Vec<f64> = Vec::new();
+    for monomial in polynomial.monomials() {
+        rows.push(column_of(model, &monomial.symbol1)? as c_int);
+        cols.push(column_of(model, &monomial.symbol2)? as c_int);
+        values.push(monomial.coefficient);
+    }
+    Ok((rows, cols, values))
+}
+
+fn c_name(name: &str) -> Result<CString, SolverError> {
+    CString::new(name).map_err(|_| {
+        SolverError::new(
+            ErrorCode::OREngineModelingException,
+            format!("Invalid name \"{}\" for Gurobi.", name),
+        )
+    })
+}
+
+fn c_attr(attr: &[u8]) -> Result<&CStr, SolverError> {
+    CStr::from_bytes_with_nul(attr).map_err(|_| {
+        SolverError::new(
+            ErrorCode::OREngineModelingException,
+            format!(
+                "Invalid attribute name \"{}\" for Gurobi.",
+                String::from_utf8_lossy(attr)
+            ),
+        )
+    })
+}
+
+impl GurobiSolver {
+    pub fn new() -> Result<Self, SolverError> {
+        load()?;
+        let mut env: *mut GRBenv = ptr::null_mut();
+        let ret_code = unsafe { GRBloadenv(&mut env, ptr::null()) };
+        if ret_code != 0 {
+            let msg = if env.is_null() {
+                format!("GRBloadenv failed with code {}.", ret_code)
+            } else {
+                let msg = unsafe { CStr::from_ptr(GRBgeterrormsg(env)) }
+                    .to_string_lossy()
+                    .into_owned();
+                unsafe {
+                    GRBfreeenv(env);
+                }
+                format!("GRBloadenv failed with code {}: {}", ret_code, msg)
+            };
+            return Err(SolverError::new(ErrorCode::OREngineEnvironmentLost, msg));
+        }
+        Ok(Self {
+            env: env,
+            model: ptr::null_mut(),
+            columns: 0,
+        })
+    }
+
+    // parameters are set on the environment of the loaded model, which is a copy of the
+    // solver environment; they are reset first, so that no option of a previous config stays
+    pub fn configure(&mut self, config: &SolverConfig) -> Result<(), SolverError> {
+        let env = self.model_env()?;
+        let ret_code = unsafe { GRBresetparams(env) };
+        self.check(
+            ret_code,
+            ErrorCode::OREngineModelingException,
+            "GRBresetparams",
+        )?;
+        if let Some(time_limit) = config.time_limit {
+            self.set_dbl_param(GRB_DBL_PAR_TIMELIMIT, time_limit.as_secs_f64())?;
+        }
+        if let Some(gap) = config.relative_gap {
+            self.set_dbl_param(GRB_DBL_PAR_MIPGAP, gap)?;
+        }
+        if let Some(gap) = config.absolute_gap {
+            self.set_dbl_param(GRB_DBL_PAR_MIPGAPABS, gap)?;
+        }
+        if let Some(threads) = config.threads {
+            self.set_int_param(
+                GRB_INT_PAR_THREADS,
+                threads.min(c_int::MAX as usize) as c_int,
+            )?;
+        }
+        if let Some(seed) = config.seed {
+            self.set_int_param(GRB_INT_PAR_SEED, seed.max(0))?;
+        }
+        if let Some(verbosity) = config.verbosity {
+            match verbosity {
+                Verbosity::Quiet | Verbosity::Error => {
+                    self.set_int_param(GRB_INT_PAR_OUTPUTFLAG, 0)?;
+                }
+                Verbosity::Normal => {
+                    self.set_int_param(GRB_INT_PAR_OUTPUTFLAG, 1)?;
+                }
+                Verbosity::Detailed => {
+                    self.set_int_param(GRB_INT_PAR_OUTPUTFLAG, 1)?;
+                    self.set_int_param(GRB_INT_PAR_DISPLAYINTERVAL, 1)?;
+                }
+            }
+        }
+        if let Some(log_file) = &config.log_file {
+            self.set_str_param(GRB_STR_PAR_LOGFILE, &log_file.to_string_lossy())?;
+        }
+        Ok(())
+    }
+
+    pub fn set_int_param(&mut self, param: &[u8], value: i32) -> Result<(), SolverError> {
+        let env = self.model_env()?;
+        let ret_code = unsafe { GRBsetintparam(env, c_attr(param)?.as_ptr(), value) };
+        self.check(
+            ret_code,
+            ErrorCode::OREngineModelingException,
+            "GRBsetintparam",
+        )
+    }
+
+    pub fn set_dbl_param(&mut self, param: &[u8], value: f64) -> Result<(), SolverError> {
+        let env = self.model_env()?;
+        let ret_code = unsafe { GRBsetdblparam(env, c_attr(param)?.as_ptr(), value) };
+        self.check(
+            ret_code,
+            ErrorCode::OREngineModelingException,
+            "GRBsetdblparam",
+        )
+    }
+
+    pub fn set_str_param(&mut self, param: &[u8], value: &str) -> Result<(), SolverError> {
+        let env = self.model_env()?;
+        let value = c_name(value)?;
+        let ret_code = unsafe { GRBsetstrparam(env, c_attr(param)?.as_ptr(), value.as_ptr()) };
+        self.check(
+            ret_code,
+            ErrorCode::OREngineModelingException,
+            "GRBsetstrparam",
+        )
+    }
+
+    pub fn load(&mut self, model: &LinearMetaModel) -> Result<(), SolverError> {
+        self.free_model();
+        unsafe {
+            let ret_code = GRBnewmodel(
+                self.env,
+                &mut self.model,
+                c_name(&model.name)?.as_ptr(),
+                0,
+                ptr::null_mut(),
+                ptr::null_mut(),
+                ptr::null_mut(),
+                ptr::null_mut(),
+                ptr::null_mut(),
+            );
+            self.check(
+                ret_code,
+                ErrorCode::OREngineModelingException,
+                "GRBnewmodel",
+            )?;
+
+            let objective = model.objective();
+            for (i, variable) in model.variables().iter().enumerate() {
+                check_range(variable)?;
+                let ret_code = GRBaddvar(
+                    self.model,
+                    0,
+                    ptr::null_mut(),
+                    ptr::null_mut(),
+                    objective.coefficient_of(&variable.symbol),
+                    value_of(variable.lower_bound),
+                    value_of(variable.upper_bound),
+                    variable_type(variable.category),
+                    c_name(model.name_of(i))?.as_ptr(),
+                );
+                self.check(ret_code, ErrorCode::OREngineModelingException, "GRBaddvar")?;
+            }
+            self.columns = model.variables().len();
+
+            for constraint in model.constraints() {
+                let expression = constraint.expression();
+                let mut indexes: Vec<c_int> = Vec::new();
+                let mut values: Vec<f64> = Vec::new();
+                for monomial in expression.monomials() {
+                    indexes.push(column_of(model, &monomial.symbol)? as c_int);
+                    values.push(monomial.coefficient);
+                }
+                let name = c_name(&constraint.name)?;
+                let (lb, ub) = (constraint.lower_bound(), constraint.upper_bound());
+                let ret_code = if lb == ub {
+                    GRBaddconstr(
+                        self.model,
+                        indexes.len() as c_int,
+                        indexes.as_mut_ptr(),
+                        values.as_mut_ptr(),
+                        GRB_EQUAL as c_char,
+                        ub,
+                        name.as_ptr(),
+                    )
+                } else if lb.is_infinite() {
+                    GRBaddconstr(
+                        self.model,
+                        indexes.len() as c_int,
+                        indexes.as_mut_ptr(),
+                        values.as_mut_ptr(),
+                        GRB_LESS_EQUAL as c_char,
+                        value_of(ub),
+                        name.as_ptr(),
+                    )
+                } else if ub.is_infinite() {
+                    GRBaddconstr(
+                        self.model,
+                        indexes.len() as c_int,
+                        indexes.as_mut_ptr(),
+                        values.as_mut_ptr(),
+                        GRB_GREATER_EQUAL as c_char,
+                        lb,
+                        name.as_ptr(),
+                    )
+                } else {
+                    GRBaddrangeconstr(
+                        self.model,
+                        indexes.len() as c_int,
+                        indexes.as_mut_ptr(),
+                        values.as_mut_ptr(),
+                        lb,
+                        ub,
+                        name.as_ptr(),
+                    )
+                };
+                self.check(
+                    ret_code,
+                    ErrorCode::OREngineModelingException,
+                    "GRBaddconstr",
+                )?;
+            }
+
+            for set in model.special_ordered_sets() {
+                let mut indexes: Vec<c_int> = Vec::new();
+                for symbol in &set.symbols {
+                    indexes.push(column_of(model, symbol)? as c_int);
+                }
+                let mut weights = set.weights.clone();
+                let mut category = match set.category {
+                    SosCategory::Sos1 => GRB_SOS_TYPE1,
+                    SosCategory::Sos2 => GRB_SOS_TYPE2,
+                } as c_int;
+                let mut begin: c_int = 0;
+                let ret_code = GRBaddsos(
+                    self.model,
+                    1,
+                    indexes.len() as c_int,
+                    &mut category,
+                    &mut begin,
+                    indexes.as_mut_ptr(),
+                    weights.as_mut_ptr(),
+                );
+                self.check(ret_code, ErrorCode::OREngineModelingException, "GRBaddsos")?;
+            }
+
+            for constraint in model.indicator_constraints() {
+                let indicator = column_of(model, &constraint.indicator)? as c_int;
+                let expression = constraint.constraint.expression();
+                let mut indexes: Vec<c_int> = Vec::new();
+                let mut values: Vec<f64> = Vec::new();
+                for monomial in expression.monomials() {
+                    indexes.push(column_of(model, &monomial.symbol)? as c_int);
+                    values.push(monomial.coefficient);
+                }
+                let name = c_name(&constraint.name)?;
+                let (lb, ub) = (
+                    constraint.constraint.lower_bound(),
+                    constraint.constraint.upper_bound(),
+                );
+                // a ranged constraint is enforced by two indicator constraints
+                let mut senses = Vec::new();
+                if lb == ub {
+                    senses.push((GRB_EQUAL as c_char, ub));
+                } else {
+                    if !lb.is_infinite() {
+                        senses.push((GRB_GREATER_EQUAL as c_char, lb));
+                    }
+                    if !ub.is_infinite() {
+                        senses.push((GRB_LESS_EQUAL as c_char, ub));
+                    }
+                }
+                for (sense, rhs) in senses {
+                    let ret_code = GRBaddgenconstrIndicator(
+                        self.model,
+                        name.as_ptr(),
+                        indicator,
+                        constraint.active_value as c_int,
+                        indexes.len() as c_int,
+                        indexes.as_ptr(),
+                        values.as_ptr(),
+                        sense,
+                        rhs,
+                    );
+                    self.check(
+                        ret_code,
+                        ErrorCode::OREngineModelingException,
+                        "GRBaddgenconstrIndicator",
+                    )?;
+                }
+            }
+
+            let ret_code = GRBsetintattr(
+                self.model,
+                GRB_INT_ATTR_MODELSENSE.as_ptr() as *const c_char,
+                match model.object_category() {
+                    ObjectCategory::Minimum => GRB_MINIMIZE as c_int,
+                    ObjectCategory::Maximum => GRB_MAXIMIZE as c_int,
+                },
+            );
+            self.check(
+                ret_code,
+                ErrorCode::OREngineModelingException,
+                "GRBsetintattr",
+            )?;
+            let ret_code = GRBsetdblattr(
+                self.model,
+                GRB_DBL_ATTR_OBJCON.as_ptr() as *const c_char,
+                objective.constant(),
+            );
+            self.check(
+                ret_code,
+                ErrorCode::OREngineModelingException,
+                "GRBsetdblattr",
+            )?;
+
+            let ret_code = GRBupdatemodel(self.model);
+            self.check(
+                ret_code,
+                ErrorCode::OREngineModelingException,
+                "GRBupdatemodel",
+            )?;
+        }
+        Ok(())
+    }
+
+    pub fn load_quadratic(&mut self, model: &QuadraticMetaModel) -> Result<(), SolverError> {
+        self.load(model.linear())?;
+        let linear = model.linear();
+        unsafe {
+            let (mut rows, mut cols, mut values) = quadratic_terms(linear, model.objective())?;
+            if !values.is_empty() {
+                let ret_code = GRBaddqpterms(
+                    self.model,
+                    values.len() as c_int,
+                    rows.as_mut_ptr(),
+                    cols.as_mut_ptr(),
+                    values.as_mut_ptr(),
+                );
+                self.check(
+                    ret_code,
+                    ErrorCode::OREngineModelingException,
+                    "GRBaddqpterms",
+                )?;
+            }
+
+            for constraint in model.quadratic_constraints() {
+                let expression = constraint.expression();
+                let mut indexes: Vec<c_int> = Vec::new();
+                let mut coefficients: Vec<f64> = Vec::new();
+                for monomial in expression.linear().monomials() {
+                    indexes.push(column_of(linear, &monomial.symbol)? as c_int);
+                    coefficients.push(monomial.coefficient);
+                }
+                let (mut rows, mut cols, mut values) = quadratic_terms(linear, &expression)?;
+                let name = c_name(&constraint.name)?;
+                // quadratic constraints have a single side, ranged ones are split in two
+                let (lb, ub) = (constraint.lower_bound(), constraint.upper_bound());
+                let mut sides: Vec<(c_char, f64)> = Vec::new();
+                if lb == ub {
+                    sides.push((GRB_EQUAL as c_char, ub));
+                } else {
+                    if !lb.is_infinite() {
+                        sides.push((GRB_GREATER_EQUAL as c_char, lb));
+                    }
+                    if !ub.is_infinite() {
+                        sides.push((GRB_LESS_EQUAL as c_char, ub));
+                    }
+                }
+                for (sense, rhs) in sides {
+                    let ret_code = GRBaddqconstr(
+                        self.model,
+                        indexes.len() as c_int,
+                        indexes.as_mut_ptr(),
+                        coefficients.as_mut_ptr(),
+                        values.len() as c_int,
+                        rows.as_mut_ptr(),
+                        cols.as_mut_ptr(),
+                        values.as_mut_ptr(),
+                        sense,
+                        rhs,
+                        name.as_ptr(),
+                    );
+                    self.check(
+                        ret_code,
+                        ErrorCode::OREngineModelingException,
+                        "GRBaddqconstr",
+                    )?;
+                }
+            }
+
+            let ret_code = GRBupdatemodel(self.model);
+            self.check(
+                ret_code,
+                ErrorCode::OREngineModelingException,
+                "GRBupdatemodel",
+            )?;
+        }
+        Ok(())
+    }
+
+    pub fn solve(&mut self) -> Result<SolverOutput, SolverError> {
+        self.check_model()?;
+        let ret_code = unsafe { GRBoptimize(self.model) };
+        self.check(ret_code, ErrorCode::OREngineSolvingException, "GRBoptimize")?;
+
+        let mut output = SolverOutput::new(status_of(self.get_int_attr(GRB_INT_ATTR_STATUS)?));
+        output.time = Duration::from_secs_f64(self.get_dbl_attr(GRB_DBL_ATTR_RUNTIME)?);
+        let is_mip = self.get_int_attr(GRB_INT_ATTR_IS_MIP)? != 0;
+        if is_mip {
+            output.nodes = self.get_dbl_attr(GRB_DBL_ATTR_NODECOUNT)? as u64;
+        }
+        if self.get_int_attr(GRB_INT_ATTR_SOLCOUNT)? > 0 && output.status != SolverStatus::Unbounded
+        {
+            output.objective = self.get_dbl_attr(GRB_DBL_ATTR_OBJVAL)?;
+            if is_mip {
+                output.best_bound = self.get_dbl_attr(GRB_DBL_ATTR_OBJBOUND)?;
+                output.gap = self.get_dbl_attr(GRB_DBL_ATTR_MIPGAP)?;
+            } else if output.status == SolverStatus::Optimal {
+                output.best_bound = output.objective;
+                output.gap = 0.;
+            }
+            output.solution = self.get_dbl_attr_array(GRB_DBL_ATTR_X, 0, self.columns)?;
+        }
+        Ok(output)
+    }
+
+    pub fn get_int_attr(&self, attr: &[u8]) -> Result<i32, SolverError> {
+        self.check_model()?;
+        let mut value: c_int = 0;
+        let ret_code = unsafe { GRBgetintattr(self.model, c_attr(attr)?.as_ptr(), &mut value) };
+        self.check(
+            ret_code,
+            ErrorCode::OREngineSolvingException,
+            "GRBgetintattr",
+        )?;
+        Ok(value)
+    }
+
+    pub fn get_dbl_attr(&self, attr: &[u8]) -> Result<f64, SolverError> {
+        self.check_model()?;
+        let mut value: f64 = 0.;
+        let ret_code = unsafe { GRBgetdblattr(self.model, c_attr(attr)?.as_ptr(), &mut value) };
+        self.check(
+            ret_code,
+            ErrorCode::OREngineSolvingException,
+            "GRBgetdblattr",
+        )?;
+        Ok(value)
+    }
+
+    pub fn get_dbl_attr_array(
+        &self,
+        attr: &[u8],
+        first: usize,
+        len: usize,
+    ) -> Result<Vec<f64>, SolverError> {
+        self.check_model()?;
+        let mut values: Vec<f64> = vec![0.; len];
+        let ret_code = unsafe {
+            GRBgetdblattrarray(
+                self.model,
+                c_attr(attr)?.as_ptr(),
+                first as c_int,
+                len as c_int,
+                values.as_mut_ptr(),
+            )
+        };
+        self.check(
+            ret_code,
+            ErrorCode::OREngineSolvingException,
+            "GRBgetdblattrarray",
+        )?;
+        Ok(values)
+    }
+
+    fn check(&self, ret_code: c_int, code: ErrorCode, operation: &str) -> Result<(), SolverError> {
+        if ret_code == 0 {
+            Ok(())
+        } else {
+            // errors after the model is created are recorded on its copy of the environment
+            let env = if self.model.is_null() {
+                self.env
+            } else {
+                unsafe { GRBgetenv(self.model) }
+            };
+            let msg = unsafe { CStr::from_ptr(GRBgeterrormsg(env)) }.to_string_lossy();
+            Err(SolverError::new(
+                code,
+                format!("{} failed with code {}: {}", operation, ret_code, msg),
+            ))
+        }
+    }
+
+    fn model_env(&self) -> Result<*mut GRBenv, SolverError> {
+        self.check_model()?;
+        Ok(unsafe { GRBgetenv(self.model) })
+    }
+
+    fn check_model(&self) -> Result<(), SolverError> {
+        if self.model.is_null() {
+            Err(SolverError::new(
+                ErrorCode::OREngineModelingException,
+                String::from("No model has been loaded into Gurobi."),
+            ))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn free_model(&mut self) {
+        if !self.model.is_null() {
+            unsafe {
+                GRBfreemodel(self.model);
+            }
+            self.model = ptr::null_mut();
+            self.columns = 0;
+        }
+    }
+}
+
+impl Solver for GurobiSolver {
+    fn name(&self) -> &'static str {
+        "gurobi"
+    }
+
+    fn solve(
+        &mut self,
+        model: &LinearMetaModel,
+        config: &SolverConfig,
+    ) -> Result<SolverOutput, SolverError> {
+        solve_encoded(model, |model| {
+            self.load(model)?;
+            self.configure(config)?;
+            GurobiSolver::solve(self)?.into_result()
+        })
+    }
+
+    fn solve_quadratic(
+        &mut self,
+        model: &QuadraticMetaModel,
+        config: &SolverConfig,
+    ) -> Result<SolverOutput, SolverError> {
+        check_quadratic_encoding(model)?;
+        self.load_quadratic(model)?;
+        self.configure(config)?;
+        GurobiSolver::solve(self)?.into_result()
+    }
+}
+
+impl Drop for GurobiSolver {
+    fn drop(&mut self) {
+        self.free_model();
+        unsafe {
+            GRBfreeenv(self.env);
+        }
+    }
+}
+
+#[test]
+fn test_status_of() {
+    assert_eq!(status_of(GRB_OPTIMAL as c_int), SolverStatus::Optimal);
+    assert_eq!(status_of(GRB_SUBOPTIMAL as c_int), SolverStatus::Feasible);
+    assert_eq!(status_of(GRB_INFEASIBLE as c_int), SolverStatus::Infeasible);
+    assert_eq!(status_of(GRB_UNBOUNDED as c_int), SolverStatus::Unbounded);
+    assert_eq!(
+        status_of(GRB_INF_OR_UNBD as c_int),
+        SolverStatus::InfeasibleOrUnbounded
+    );
+    assert_eq!(status_of(GRB_TIME_LIMIT as c_int), SolverStatus::TimeLimit);
+    assert_eq!(status_of(GRB_NODE_LIMIT as c_int), SolverStatus::NodeLimit);
+    assert_eq!(
+        status_of(GRB_SOLUTION_LIMIT as c_int),
+        SolverStatus::Interrupted
+    );
+    assert_eq!(status_of(GRB_LOADED as c_int), SolverStatus::Error);
+
+    assert_eq!(value_of(f64::INFINITY), GRB_INFINITY);
+    assert_eq!(value_of(f64::NEG_INFINITY), -GRB_INFINITY);
+    assert_eq!(value_of(1.5), 1.5);
+}
@@ -0,0 +1,80 @@
+macro_rules! solver_api {
+    (
+        $display:literal, $env:literal, [$($library:literal),*];
+        $(fn $name:ident($($arg:ident: $type:ty),* $(,)?) $(-> $ret:ty)?;)*
+    ) => {
+        #[cfg(feature = "dynamic")]
+        pub struct Library {
+            _library: ::libloading::Library,
+            $($name: unsafe extern "C" fn($($type),*) $(-> $ret)?,)*
+        }
+
+        #[cfg(feature = "dynamic")]
+        impl Library {
+            fn open() -> Result<Self, String> {
+                let mut candidates: Vec<::std::ffi::OsString> = Vec::new();
+                if let Some(path) = ::std::env::var_os($env) {
+                    candidates.push(path);
+                }
+                $(candidates.push(::libloading::library_filename($library));)*
+
+                let mut errors: Vec<String> = Vec::new();
+                for candidate in candidates {
+                    match unsafe { ::libloading::Library::new(&candidate) } {
+                        Ok(library) => unsafe {
+                            return Ok(Self {
+                                $($name: *library
+                                    .get::<unsafe extern "C" fn($($type),*) $(-> $ret)?>(
+                                        concat!(stringify!($name), "\0").as_bytes(),
+                                    )
+                                    .map_err(|err| format!("{}: {}", $display, err))?,)*
+                                _library: library,
+                            });
+                        },
+                        Err(err) => errors.push(format!("{:?}: {}", candidate, err)),
+                    }
+                }
+                Err(format!(
+                    "{} shared library cannot be loaded, set {} to its path ({}).",
+                    $display,
+                    $env,
+                    errors.join("; ")
+                ))
+            }
+        }
+
+        #[cfg(feature = "dynamic")]
+        fn library() -> Result<&'static Library, $crate::core::backend::error::SolverError> {
+            static LIBRARY: ::std::sync::OnceLock<Result<Library, String>> =
+                ::std::sync::OnceLock::new();
+            match LIBRARY.get_or_init(Library::open) {
+                Ok(library) => Ok(library),
+                Err(msg) => Err($crate::core::backend::error::SolverError::new(
+                    ::ospf_rust_base::ErrorCode::SolverNotFound,
+                    msg.clone(),
+                )),
+            }
+        }
+
+        $(
+            #[cfg(feature = "dynamic")]
+            pub unsafe fn $name($($arg: $type),*) $(-> $ret)? {
+                match library() {
+                    Ok(library) => (library.$name)($($arg),*),
+                    Err(err) => panic!("{}", err),
+                }
+            }
+        )*
+
+        pub fn load() -> Result<(), $crate::core::backend::error::SolverError> {
+            #[cfg(feature = "dynamic")]
+            {
+                library().map(|_| ())
+            }
+            #[cfg(not(feature = "dynamic"))]
+            {
+                Ok(())
+            }
+        }
+    };
+}
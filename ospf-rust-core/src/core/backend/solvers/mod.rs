@@ -0,0 +1,55 @@
+#[macro_use]
+mod loader;
+
+#[cfg(feature = "glpk")]
+#[allow(
+    non_upper_case_globals,
+    non_camel_case_types,
+    non_snake_case,
+    dead_code
+)]
+pub mod glpk;
+#[cfg(feature = "glpk")]
+#[allow(non_snake_case)]
+pub mod glpk_api;
+#[cfg(feature = "glpk")]
+pub mod glpk_solver;
+
+#[cfg(feature = "gurobi")]
+#[allow(
+    non_upper_case_globals,
+    non_camel_case_types,
+    non_snake_case,
+    dead_code
+)]
+pub mod gurobi;
+#[cfg(feature = "gurobi")]
+#[allow(non_snake_case)]
+pub mod gurobi_api;
+#[cfg(feature = "gurobi")]
+pub mod gurobi_solver;
+
+#[cfg(feature = "scip")]
+#[allow(
+    non_upper_case_globals,
+    non_camel_case_types,
+    non_snake_case,
+    dead_code
+)]
+pub mod scip;
+#[cfg(feature = "scip")]
+#[allow(non_snake_case)]
+pub mod scip_api;
+#[cfg(feature = "scip")]
+#[allow(non_snake_case)]
+pub mod scip_plugins;
+#[cfg(feature = "scip")]
+#[allow(non_upper_case_globals)]
+pub mod scip_solver;
+
+#[cfg(feature = "glpk")]
+pub use glpk_solver::GlpkSolver;
+#[cfg(feature = "gurobi")]
+pub use gurobi_solver::GurobiSolver;
+#[cfg(feature = "scip")]
+pub use scip_solver::ScipSolver;
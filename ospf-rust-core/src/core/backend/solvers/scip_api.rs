@@ -0,0 +1,99 @@
+pub use super::scip::*;
+#[cfg(not(feature = "dynamic"))]
+pub use super::scip_plugins::*;
+#[cfg(feature = "dynamic")]
+use std::os::raw::*;
+
+solver_api! {
+    "SCIP", "SCIP_LIBRARY", ["scip", "libscip"];
+    fn SCIPcreate(scip: *mut *mut SCIP) -> SCIP_RETCODE;
+    fn SCIPfree(scip: *mut *mut SCIP) -> SCIP_RETCODE;
+    fn SCIPincludeDefaultPlugins(scip: *mut SCIP) -> SCIP_RETCODE;
+    fn SCIPfreeProb(scip: *mut SCIP) -> SCIP_RETCODE;
+    fn SCIPcreateProbBasic(scip: *mut SCIP, name: *const c_char) -> SCIP_RETCODE;
+    fn SCIPsetObjsense(scip: *mut SCIP, objsense: SCIP_OBJSENSE) -> SCIP_RETCODE;
+    fn SCIPaddOrigObjoffset(scip: *mut SCIP, addval: f64) -> SCIP_RETCODE;
+    fn SCIPinfinity(scip: *mut SCIP) -> f64;
+    fn SCIPcreateVarBasic(
+        scip: *mut SCIP,
+        var: *mut *mut SCIP_VAR,
+        name: *const c_char,
+        lb: f64,
+        ub: f64,
+        obj: f64,
+        vartype: SCIP_VARTYPE,
+    ) -> SCIP_RETCODE;
+    fn SCIPaddVar(scip: *mut SCIP, var: *mut SCIP_VAR) -> SCIP_RETCODE;
+    fn SCIPreleaseVar(scip: *mut SCIP, var: *mut *mut SCIP_VAR) -> SCIP_RETCODE;
+    fn SCIPcreateConsBasicLinear(
+        scip: *mut SCIP,
+        cons: *mut *mut SCIP_CONS,
+        name: *const c_char,
+        nvars: c_int,
+        vars: *mut *mut SCIP_VAR,
+        vals: *mut f64,
+        lhs: f64,
+        rhs: f64,
+    ) -> SCIP_RETCODE;
+    fn SCIPaddCons(scip: *mut SCIP, cons: *mut SCIP_CONS) -> SCIP_RETCODE;
+    fn SCIPreleaseCons(scip: *mut SCIP, cons: *mut *mut SCIP_CONS) -> SCIP_RETCODE;
+    fn SCIPsolve(scip: *mut SCIP) -> SCIP_RETCODE;
+    fn SCIPgetStatus(scip: *mut SCIP) -> SCIP_STATUS;
+    fn SCIPgetNSols(scip: *mut SCIP) -> c_int;
+    fn SCIPgetBestSol(scip: *mut SCIP) -> *mut SCIP_SOL;
+    fn SCIPgetSolOrigObj(scip: *mut SCIP, sol: *mut SCIP_SOL) -> f64;
+    fn SCIPgetSolVal(scip: *mut SCIP, sol: *mut SCIP_SOL, var: *mut SCIP_VAR) -> f64;
+    fn SCIPgetSolvingTime(scip: *mut SCIP) -> f64;
+    fn SCIPgetNTotalNodes(scip: *mut SCIP) -> c_longlong;
+    fn SCIPgetDualbound(scip: *mut SCIP) -> f64;
+    fn SCIPgetGap(scip: *mut SCIP) -> f64;
+    fn SCIPresetParams(scip: *mut SCIP) -> SCIP_RETCODE;
+    fn SCIPsetRealParam(scip: *mut SCIP, name: *const c_char, value: f64) -> SCIP_RETCODE;
+    fn SCIPsetIntParam(scip: *mut SCIP, name: *const c_char, value: c_int) -> SCIP_RETCODE;
+    fn SCIPsetMessagehdlrLogfile(scip: *mut SCIP, filename: *const c_char);
+    fn SCIPcreateConsBasicQuadratic(
+        scip: *mut SCIP,
+        cons: *mut *mut SCIP_CONS,
+        name: *const c_char,
+        nlinvars: c_int,
+        linvars: *mut *mut SCIP_VAR,
+        lincoefs: *mut f64,
+        nquadterms: c_int,
+        quadvars1: *mut *mut SCIP_VAR,
+        quadvars2: *mut *mut SCIP_VAR,
+        quadcoefs: *mut f64,
+        lhs: f64,
+        rhs: f64,
+    ) -> SCIP_RETCODE;
+    fn SCIPcreateConsBasicSOS1(
+        scip: *mut SCIP,
+        cons: *mut *mut SCIP_CONS,
+        name: *const c_char,
+        nvars: c_int,
+        vars: *mut *mut SCIP_VAR,
+        weights: *mut f64,
+    ) -> SCIP_RETCODE;
+    fn SCIPcreateConsBasicSOS2(
+        scip: *mut SCIP,
+        cons: *mut *mut SCIP_CONS,
+        name: *const c_char,
+        nvars: c_int,
+        vars: *mut *mut SCIP_VAR,
+        weights: *mut f64,
+    ) -> SCIP_RETCODE;
+    fn SCIPgetNegatedVar(
+        scip: *mut SCIP,
+        var: *mut SCIP_VAR,
+        negvar: *mut *mut SCIP_VAR,
+    ) -> SCIP_RETCODE;
+    fn SCIPcreateConsBasicIndicator(
+        scip: *mut SCIP,
+        cons: *mut *mut SCIP_CONS,
+        name: *const c_char,
+        binvar: *mut SCIP_VAR,
+        nvars: c_int,
+        vars: *mut *mut SCIP_VAR,
+        vals: *mut f64,
+        rhs: f64,
+    ) -> SCIP_RETCODE;
+}
@@ -0,0 +1,65 @@
+use super::scip::*;
+
+extern "C" {
+    pub fn SCIPincludeDefaultPlugins(scip: *mut SCIP) -> SCIP_RETCODE;
+}
+extern "C" {
+    pub fn SCIPcreateConsBasicLinear(
+        scip: *mut SCIP,
+        cons: *mut *mut SCIP_CONS,
+        name: *const ::std::os::raw::c_char,
+        nvars: ::std::os::raw::c_int,
+        vars: *mut *mut SCIP_VAR,
+        vals: *mut f64,
+        lhs: f64,
+        rhs: f64,
+    ) -> SCIP_RETCODE;
+}
+extern "C" {
+    pub fn SCIPcreateConsBasicQuadratic(
+        scip: *mut SCIP,
+        cons: *mut *mut SCIP_CONS,
+        name: *const ::std::os::raw::c_char,
+        nlinvars: ::std::os::raw::c_int,
+        linvars: *mut *mut SCIP_VAR,
+        lincoefs: *mut f64,
+        nquadterms: ::std::os::raw::c_int,
+        quadvars1: *mut *mut SCIP_VAR,
+        quadvars2: *mut *mut SCIP_VAR,
+        quadcoefs: *mut f64,
+        lhs: f64,
+        rhs: f64,
+    ) -> SCIP_RETCODE;
+}
+extern "C" {
+    pub fn SCIPcreateConsBasicSOS1(
+        scip: *mut SCIP,
+        cons: *mut *mut SCIP_CONS,
+        name: *const ::std::os::raw::c_char,
+        nvars: ::std::os::raw::c_int,
+        vars: *mut *mut SCIP_VAR,
+        weights: *mut f64,
+    ) -> SCIP_RETCODE;
+}
+extern "C" {
+    pub fn SCIPcreateConsBasicSOS2(
+        scip: *mut SCIP,
+        cons: *mut *mut SCIP_CONS,
+        name: *const ::std::os::raw::c_char,
+        nvars: ::std::os::raw::c_int,
+        vars: *mut *mut SCIP_VAR,
+        weights: *mut f64,
+    ) -> SCIP_RETCODE;
+}
+extern "C" {
+    pub fn SCIPcreateConsBasicIndicator(
+        scip: *mut SCIP,
+        cons: *mut *mut SCIP_CONS,
+        name: *const ::std::os::raw::c_char,
+        binvar: *mut SCIP_VAR,
+        nvars: ::std::os::raw::c_int,
+        vars: *mut *mut SCIP_VAR,
+        vals: *mut f64,
+        rhs: f64,
+    ) -> SCIP_RETCODE;
+}
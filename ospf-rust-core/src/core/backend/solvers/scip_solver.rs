@@ -0,0 +1,697 @@
+use super::super::config::{SolverConfig, Verbosity};
+use super::super::error::SolverError;
+use super::super::output::*;
+use super::super::solver::{check_quadratic_encoding, solve_encoded, Solver};
+use super::scip_api::*;
+use crate::core::frontend::constraint::{IndicatorConstraint, SosCategory};
+use crate::core::frontend::expression::{LinearPolynomial, QuadraticMonomial, VariableSymbol};
+use crate::core::frontend::model::*;
+use crate::core::frontend::variable::VariableCategory;
+use ospf_rust_base::ErrorCode;
+use std::ffi::CString;
+use std::os::raw::c_int;
+use std::ptr;
+use std::time::Duration;
+
+pub struct ScipSolver {
+    scip: *mut SCIP,
+    variables: Vec<*mut SCIP_VAR>,
+    constraints: Vec<*mut SCIP_CONS>,
+    // variables introduced by the reformulations, not part of the solution
+    auxiliary_variables: Vec<*mut SCIP_VAR>,
+}
+
+fn retcode_name(retcode: SCIP_RETCODE) -> &'static str {
+    match retcode {
+        SCIP_Retcode_SCIP_OKAY => "SCIP_OKAY",
+        SCIP_Retcode_SCIP_ERROR => "SCIP_ERROR",
+        SCIP_Retcode_SCIP_NOMEMORY => "SCIP_NOMEMORY",
+        SCIP_Retcode_SCIP_READERROR => "SCIP_READERROR",
+        SCIP_Retcode_SCIP_WRITEERROR => "SCIP_WRITEERROR",
+        SCIP_Retcode_SCIP_NOFILE => "SCIP_NOFILE",
+        SCIP_Retcode_SCIP_FILECREATEERROR => "SCIP_FILECREATEERROR",
+        SCIP_Retcode_SCIP_LPERROR => "SCIP_LPERROR",
+        SCIP_Retcode_SCIP_NOPROBLEM => "SCIP_NOPROBLEM",
+        SCIP_Retcode_SCIP_INVALIDCALL => "SCIP_INVALIDCALL",
+        SCIP_Retcode_SCIP_INVALIDDATA => "SCIP_INVALIDDATA",
+        SCIP_Retcode_SCIP_INVALIDRESULT => "SCIP_INVALIDRESULT",
+        SCIP_Retcode_SCIP_PLUGINNOTFOUND => "SCIP_PLUGINNOTFOUND",
+        SCIP_Retcode_SCIP_PARAMETERUNKNOWN => "SCIP_PARAMETERUNKNOWN",
+        SCIP_Retcode_SCIP_PARAMETERWRONGTYPE => "SCIP_PARAMETERWRONGTYPE",
+        SCIP_Retcode_SCIP_PARAMETERWRONGVAL => "SCIP_PARAMETERWRONGVAL",
+        SCIP_Retcode_SCIP_KEYALREADYEXISTING => "SCIP_KEYALREADYEXISTING",
+        SCIP_Retcode_SCIP_MAXDEPTHLEVEL => "SCIP_MAXDEPTHLEVEL",
+        SCIP_Retcode_SCIP_BRANCHERROR => "SCIP_BRANCHERROR",
+        SCIP_Retcode_SCIP_NOTIMPLEMENTED => "SCIP_NOTIMPLEMENTED",
+        _ => "unknown SCIP_Retcode",
+    }
+}
+
+fn check(retcode: SCIP_RETCODE, code: ErrorCode, operation: &str) -> Result<(), SolverError> {
+    if retcode == SCIP_Retcode_SCIP_OKAY {
+        Ok(())
+    } else {
+        Err(SolverError::new(
+            code,
+            format!(
+                "{} failed with {} ({}).",
+                operation,
+                retcode_name(retcode),
+                retcode
+            ),
+        ))
+    }
+}
+
+fn modeling(retcode: SCIP_RETCODE, operation: &str) -> Result<(), SolverError> {
+    check(retcode, ErrorCode::OREngineModelingException, operation)
+}
+
+fn solving(retcode: SCIP_RETCODE, operation: &str) -> Result<(), SolverError> {
+    check(retcode, ErrorCode::OREngineSolvingException, operation)
+}
+
+fn variable_type(category: VariableCategory) -> SCIP_Vartype {
+    match category {
+        VariableCategory::Binary => SCIP_Vartype_SCIP_VARTYPE_BINARY,
+        VariableCategory::Integer | VariableCategory::SemiInteger => {
+            SCIP_Vartype_SCIP_VARTYPE_INTEGER
+        }
+        VariableCategory::Continuous | VariableCategory::SemiContinuous => {
+            SCIP_Vartype_SCIP_VARTYPE_CONTINUOUS
+        }
+    }
+}
+
+// a semi variable is 0 or in [lb, ub], so its column takes the hull of both
+fn column_bounds(variable: &ModelVariable) -> (f64, f64) {
+    if variable.category.is_semi() {
+        (variable.lower_bound.min(0.), variable.upper_bound.max(0.))
+    } else {
+        (variable.lower_bound, variable.upper_bound)
+    }
+}
+
+fn status_of(status: SCIP_Status) -> SolverStatus {
+    match status {
+        SCIP_Status_SCIP_STATUS_OPTIMAL => SolverStatus::Optimal,
+        SCIP_Status_SCIP_STATUS_GAPLIMIT => SolverStatus::Feasible,
+        SCIP_Status_SCIP_STATUS_INFEASIBLE => SolverStatus::Infeasible,
+        SCIP_Status_SCIP_STATUS_UNBOUNDED => SolverStatus::Unbounded,
+        SCIP_Status_SCIP_STATUS_INFORUNBD => SolverStatus::InfeasibleOrUnbounded,
+        SCIP_Status_SCIP_STATUS_TIMELIMIT => SolverStatus::TimeLimit,
+        SCIP_Status_SCIP_STATUS_NODELIMIT
+        | SCIP_Status_SCIP_STATUS_TOTALNODELIMIT
+        | SCIP_Status_SCIP_STATUS_STALLNODELIMIT => SolverStatus::NodeLimit,
+        SCIP_Status_SCIP_STATUS_USERINTERRUPT
+        | SCIP_Status_SCIP_STATUS_MEMLIMIT
+        | SCIP_Status_SCIP_STATUS_SOLLIMIT
+        | SCIP_Status_SCIP_STATUS_BESTSOLLIMIT
+        | SCIP_Status_SCIP_STATUS_RESTARTLIMIT
+        | SCIP_Status_SCIP_STATUS_TERMINATE => SolverStatus::Interrupted,
+        _ => SolverStatus::Error,
+    }
+}
+
+fn check_range(variable: &ModelVariable) -> Result<(), SolverError> {
+    if variable.empty() {
+        Err(SolverError::new(
+            ErrorCode::ORModelNoSolution,
+            format!(
+                "Variable {} has an empty range [{}, {}].",
+                variable.symbol, variable.lower_bound, variable.upper_bound
+            ),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+fn column_of(model: &LinearMetaModel, symbol: &VariableSymbol) -> Result<usize, SolverError> {
+    model.column_of(symbol).ok_or_else(|| {
+        SolverError::new(
+            ErrorCode::OREngineModelingException,
+            format!("Variable {} is not registered in the model.", symbol),
+        )
+    })
+}
+
+fn c_name(name: &str) -> Result<CString, SolverError> {
+    CString::new(name).map_err(|_| {
+        SolverError::new(
+            ErrorCode::OREngineModelingException,
+            format!("Invalid name \"{}\" for SCIP.", name),
+        )
+    })
+}
+
+impl ScipSolver {
+    pub fn new() -> Result<Self, SolverError> {
+        load()?;
+        let mut scip: *mut SCIP = ptr::null_mut();
+        unsafe {
+            modeling(SCIPcreate(&mut scip), "SCIPcreate")?;
+        }
+        let ret = Self {
+            scip: scip,
+            variables: Vec::new(),
+            constraints: Vec::new(),
+            auxiliary_variables: Vec::new(),
+        };
+        unsafe {
+            modeling(
+                SCIPincludeDefaultPlugins(ret.scip),
+                "SCIPincludeDefaultPlugins",
+            )?;
+        }
+        Ok(ret)
+    }
+
+    // SCIPsolve runs single-threaded, threads are ignored;
+    // parameters are reset first, so that no option of a previous config stays
+    pub fn configure(&mut self, config: &SolverConfig) -> Result<(), SolverError> {
+        unsafe {
+            modeling(SCIPresetParams(self.scip), "SCIPresetParams")?;
+            SCIPsetMessagehdlrLogfile(self.scip, ptr::null());
+        }
+        if let Some(time_limit) = config.time_limit {
+            self.set_real_param("limits/time", time_limit.as_secs_f64())?;
+        }
+        if let Some(gap) = config.relative_gap {
+            self.set_real_param("limits/gap", gap)?;
+        }
+        if let Some(gap) = config.absolute_gap {
+            self.set_real_param("limits/absgap", gap)?;
+        }
+        if let Some(seed) = config.seed {
+            self.set_int_param("randomization/randomseedshift", seed)?;
+        }
+        if let Some(verbosity) = config.verbosity {
+            self.set_int_param(
+                "display/verblevel",
+                match verbosity {
+                    // errors are printed at every level
+                    Verbosity::Quiet | Verbosity::Error => SCIP_VerbLevel_SCIP_VERBLEVEL_NONE,
+                    Verbosity::Normal => SCIP_VerbLevel_SCIP_VERBLEVEL_NORMAL,
+                    Verbosity::Detailed => SCIP_VerbLevel_SCIP_VERBLEVEL_HIGH,
+                } as c_int,
+            )?;
+        }
+        if let Some(log_file) = &config.log_file {
+            let log_file = c_name(&log_file.to_string_lossy())?;
+            unsafe {
+                SCIPsetMessagehdlrLogfile(self.scip, log_file.as_ptr());
+            }
+        }
+        Ok(())
+    }
+
+    pub fn set_real_param(&mut self, name: &str, value: f64) -> Result<(), SolverError> {
+        unsafe {
+            modeling(
+                SCIPsetRealParam(self.scip, c_name(name)?.as_ptr(), value),
+                "SCIPsetRealParam",
+            )
+        }
+    }
+
+    pub fn set_int_param(&mut self, name: &str, value: c_int) -> Result<(), SolverError> {
+        unsafe {
+            modeling(
+                SCIPsetIntParam(self.scip, c_name(name)?.as_ptr(), value),
+                "SCIPsetIntParam",
+            )
+        }
+    }
+
+    pub fn load(&mut self, model: &LinearMetaModel) -> Result<(), SolverError> {
+        self.release()?;
+        unsafe {
+            modeling(SCIPfreeProb(self.scip), "SCIPfreeProb")?;
+            modeling(
+                SCIPcreateProbBasic(self.scip, c_name(&model.name)?.as_ptr()),
+                "SCIPcreateProbBasic",
+            )?;
+            modeling(
+                SCIPsetObjsense(
+                    self.scip,
+                    match model.object_category() {
+                        ObjectCategory::Minimum => SCIP_Objsense_SCIP_OBJSENSE_MINIMIZE,
+                        ObjectCategory::Maximum => SCIP_Objsense_SCIP_OBJSENSE_MAXIMIZE,
+                    },
+                ),
+                "SCIPsetObjsense",
+            )?;
+
+            let objective = model.objective();
+            for (i, variable) in model.variables().iter().enumerate() {
+                check_range(variable)?;
+                let (lb, ub) = column_bounds(variable);
+                let mut var: *mut SCIP_VAR = ptr::null_mut();
+                modeling(
+                    SCIPcreateVarBasic(
+                        self.scip,
+                        &mut var,
+                        c_name(model.name_of(i))?.as_ptr(),
+                        self.value_of(lb),
+                        self.value_of(ub),
+                        objective.coefficient_of(&variable.symbol),
+                        variable_type(variable.category),
+                    ),
+                    "SCIPcreateVarBasic",
+                )?;
+                self.variables.push(var);
+                modeling(SCIPaddVar(self.scip, var), "SCIPaddVar")?;
+            }
+            if objective.constant() != 0. {
+                modeling(
+                    SCIPaddOrigObjoffset(self.scip, objective.constant()),
+                    "SCIPaddOrigObjoffset",
+                )?;
+            }
+
+            for constraint in model.constraints() {
+                let expression = constraint.expression();
+                let mut vars: Vec<*mut SCIP_VAR> = Vec::new();
+                let mut vals: Vec<f64> = Vec::new();
+                for monomial in expression.monomials() {
+                    vars.push(self.variables[column_of(model, &monomial.symbol)?]);
+                    vals.push(monomial.coefficient);
+                }
+                let mut cons: *mut SCIP_CONS = ptr::null_mut();
+                modeling(
+                    SCIPcreateConsBasicLinear(
+                        self.scip,
+                        &mut cons,
+                        c_name(&constraint.name)?.as_ptr(),
+                        vars.len() as c_int,
+                        vars.as_mut_ptr(),
+                        vals.as_mut_ptr(),
+                        self.value_of(constraint.lower_bound()),
+                        self.value_of(constraint.upper_bound()),
+                    ),
+                    "SCIPcreateConsBasicLinear",
+                )?;
+                self.constraints.push(cons);
+                modeling(SCIPaddCons(self.scip, cons), "SCIPaddCons")?;
+            }
+
+            for set in model.special_ordered_sets() {
+                let mut vars: Vec<*mut SCIP_VAR> = Vec::new();
+                for symbol in &set.symbols {
+                    vars.push(self.variables[column_of(model, symbol)?]);
+                }
+                let mut weights = set.weights.clone();
+                let name = c_name(&set.name)?;
+                let mut cons: *mut SCIP_CONS = ptr::null_mut();
+                match set.category {
+                    SosCategory::Sos1 => modeling(
+                        SCIPcreateConsBasicSOS1(
+                            self.scip,
+                            &mut cons,
+                            name.as_ptr(),
+                            vars.len() as c_int,
+                            vars.as_mut_ptr(),
+                            weights.as_mut_ptr(),
+                        ),
+                        "SCIPcreateConsBasicSOS1",
+                    )?,
+                    SosCategory::Sos2 => modeling(
+                        SCIPcreateConsBasicSOS2(
+                            self.scip,
+                            &mut cons,
+                            name.as_ptr(),
+                            vars.len() as c_int,
+                            vars.as_mut_ptr(),
+                            weights.as_mut_ptr(),
+                        ),
+                        "SCIPcreateConsBasicSOS2",
+                    )?,
+                }
+                self.constraints.push(cons);
+                modeling(SCIPaddCons(self.scip, cons), "SCIPaddCons")?;
+            }
+
+            for constraint in model.indicator_constraints() {
+                self.add_indicator_constraint(model, constraint)?;
+            }
+
+            for (i, variable) in model.variables().iter().enumerate() {
+                if variable.category.is_semi() {
+                    self.linearize_semi_variable(self.variables[i], variable)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    // lb * y <= x <= ub * y with an auxiliary binary y,
+    // unneeded if 0 is already in [lb, ub]
+    unsafe fn linearize_semi_variable(
+        &mut self,
+        var: *mut SCIP_VAR,
+        variable: &ModelVariable,
+    ) -> Result<(), SolverError> {
+        let (lb, ub) = (variable.lower_bound, variable.upper_bound);
+        if lb <= 0. && 0. <= ub {
+            return Ok(());
+        }
+        if !lb.is_finite() || !ub.is_finite() {
+            return Err(SolverError::new(
+                ErrorCode::OREngineModelingException,
+                format!(
+                    "Semi variable {} needs a finite range to be linearized, not [{}, {}].",
+                    variable.symbol, lb, ub
+                ),
+            ));
+        }
+        let mut indicator: *mut SCIP_VAR = ptr::null_mut();
+        modeling(
+            SCIPcreateVarBasic(
+                self.scip,
+                &mut indicator,
+                c_name(&format!("{}_nonzero", variable.symbol))?.as_ptr(),
+                0.,
+                1.,
+                0.,
+                SCIP_Vartype_SCIP_VARTYPE_BINARY,
+            ),
+            "SCIPcreateVarBasic",
+        )?;
+        self.auxiliary_variables.push(indicator);
+        modeling(SCIPaddVar(self.scip, indicator), "SCIPaddVar")?;
+
+        for (coefficient, lhs, rhs) in [(-ub, f64::NEG_INFINITY, 0.), (-lb, 0., f64::INFINITY)] {
+            let mut vars: [*mut SCIP_VAR; 2] = [var, indicator];
+            let mut vals: [f64; 2] = [1., coefficient];
+            let mut cons: *mut SCIP_CONS = ptr::null_mut();
+            modeling(
+                SCIPcreateConsBasicLinear(
+                    self.scip,
+                    &mut cons,
+                    c_name(&format!("{}_semi", variable.symbol))?.as_ptr(),
+                    2,
+                    vars.as_mut_ptr(),
+                    vals.as_mut_ptr(),
+                    self.value_of(lhs),
+                    self.value_of(rhs),
+                ),
+                "SCIPcreateConsBasicLinear",
+            )?;
+            self.constraints.push(cons);
+            modeling(SCIPaddCons(self.scip, cons), "SCIPaddCons")?;
+        }
+        Ok(())
+    }
+
+    // SCIP only takes indicator -> a^T x <= rhs, so a lower bound is passed negated
+    // as a second constraint
+    unsafe fn add_indicator_constraint(
+        &mut self,
+        model: &LinearMetaModel,
+        constraint: &IndicatorConstraint,
+    ) -> Result<(), SolverError> {
+        let mut indicator = self.variables[column_of(model, &constraint.indicator)?];
+        if !constraint.active_value {
+            let mut negated: *mut SCIP_VAR = ptr::null_mut();
+            modeling(
+                SCIPgetNegatedVar(self.scip, indicator, &mut negated),
+                "SCIPgetNegatedVar",
+            )?;
+            indicator = negated;
+        }
+        let (mut vars, vals) = self.linear_terms(model, &constraint.constraint.expression())?;
+        let (lb, ub) = (
+            constraint.constraint.lower_bound(),
+            constraint.constraint.upper_bound(),
+        );
+        let mut sides = Vec::new();
+        if !ub.is_infinite() {
+            sides.push((1., ub));
+        }
+        if !lb.is_infinite() {
+            sides.push((-1., -lb));
+        }
+        for (sign, rhs) in sides {
+            let mut vals: Vec<f64> = vals.iter().map(|value| sign * value).collect();
+            let mut cons: *mut SCIP_CONS = ptr::null_mut();
+            modeling(
+                SCIPcreateConsBasicIndicator(
+                    self.scip,
+                    &mut cons,
+                    c_name(&constraint.name)?.as_ptr(),
+                    indicator,
+                    vars.len() as c_int,
+                    vars.as_mut_ptr(),
+                    vals.as_mut_ptr(),
+                    rhs,
+                ),
+                "SCIPcreateConsBasicIndicator",
+            )?;
+            self.constraints.push(cons);
+            modeling(SCIPaddCons(self.scip, cons), "SCIPaddCons")?;
+        }
+        Ok(())
+    }
+
+    pub fn load_quadratic(&mut self, model: &QuadraticMetaModel) -> Result<(), SolverError> {
+        self.load(model.linear())?;
+        let linear = model.linear();
+        unsafe {
+            let objective = model.objective();
+            if !objective.is_linear() {
+                // SCIP only takes linear objectives, so the quadratic terms bound an auxiliary
+                // variable which is added to the objective instead
+                let mut var: *mut SCIP_VAR = ptr::null_mut();
+                modeling(
+                    SCIPcreateVarBasic(
+                        self.scip,
+                        &mut var,
+                        c_name("quadratic_objective")?.as_ptr(),
+                        self.value_of(f64::NEG_INFINITY),
+                        self.value_of(f64::INFINITY),
+                        1.,
+                        SCIP_Vartype_SCIP_VARTYPE_CONTINUOUS,
+                    ),
+                    "SCIPcreateVarBasic",
+                )?;
+                self.auxiliary_variables.push(var);
+                modeling(SCIPaddVar(self.scip, var), "SCIPaddVar")?;
+                let (lhs, rhs) = match model.object_category() {
+                    ObjectCategory::Minimum => (f64::NEG_INFINITY, 0.),
+                    ObjectCategory::Maximum => (0., f64::INFINITY),
+                };
+                self.add_quadratic_constraint(
+                    linear,
+                    "quadratic_objective",
+                    (vec![var], vec![-1.]),
+                    objective.monomials(),
+                    lhs,
+                    rhs,
+                )?;
+            }
+
+            for constraint in model.quadratic_constraints() {
+                let expression = constraint.expression();
+                let terms = self.linear_terms(linear, expression.linear())?;
+                self.add_quadratic_constraint(
+                    linear,
+                    &constraint.name,
+                    terms,
+                    expression.monomials(),
+                    constraint.lower_bound(),
+                    constraint.upper_bound(),
+                )?;
+            }
+        }
+        Ok(())
+    }
+
+    pub fn solve(&mut self) -> Result<SolverOutput, SolverError> {
+        unsafe {
+            solving(SCIPsolve(self.scip), "SCIPsolve")?;
+
+            let mut output = SolverOutput::new(status_of(SCIPgetStatus(self.scip)));
+            output.time = Duration::from_secs_f64(SCIPgetSolvingTime(self.scip));
+            output.nodes = SCIPgetNTotalNodes(self.scip) as u64;
+            if SCIPgetNSols(self.scip) > 0 && output.status != SolverStatus::Unbounded {
+                let sol = SCIPgetBestSol(self.scip);
+                output.objective = SCIPgetSolOrigObj(self.scip, sol);
+                output.best_bound = SCIPgetDualbound(self.scip);
+                output.gap = SCIPgetGap(self.scip);
+                output.solution = self
+                    .variables
+                    .iter()
+                    .map(|var| SCIPgetSolVal(self.scip, sol, *var))
+                    .collect();
+            }
+            Ok(output)
+        }
+    }
+
+    fn linear_terms(
+        &self,
+        model: &LinearMetaModel,
+        polynomial: &LinearPolynomial,
+    ) -> Result<(Vec<*mut SCIP_VAR>, Vec<f64>), SolverError> {
+        let mut vars: Vec<*mut SCIP_VAR> = Vec::new();
+        let mut vals: Vec<f64> = Vec::new();
+        for monomial in polynomial.monomials() {
+            vars.push(self.variables[column_of(model, &monomial.symbol)?]);
+            vals.push(monomial.coefficient);
+        }
+        Ok((vars, vals))
+    }
+
+    unsafe fn add_quadratic_constraint(
+        &mut self,
+        model: &LinearMetaModel,
+        name: &str,
+        linear: (Vec<*mut SCIP_VAR>, Vec<f64>),
+        monomials: &[QuadraticMonomial],
+        lhs: f64,
+        rhs: f64,
+    ) -> Result<(), SolverError> {
+        let (mut vars, mut vals) = linear;
+        let mut vars1: Vec<*mut SCIP_VAR> = Vec::new();
+        let mut vars2: Vec<*mut SCIP_VAR> = Vec::new();
+        let mut coefs: Vec<f64> = Vec::new();
+        for monomial in monomials {
+            vars1.push(self.variables[column_of(model, &monomial.symbol1)?]);
+            vars2.push(self.variables[column_of(model, &monomial.symbol2)?]);
+            coefs.push(monomial.coefficient);
+        }
+        let mut cons: *mut SCIP_CONS = ptr::null_mut();
+        modeling(
+            SCIPcreateConsBasicQuadratic(
+                self.scip,
+                &mut cons,
+                c_name(name)?.as_ptr(),
+                vars.len() as c_int,
+                vars.as_mut_ptr(),
+                vals.as_mut_ptr(),
+                coefs.len() as c_int,
+                vars1.as_mut_ptr(),
+                vars2.as_mut_ptr(),
+                coefs.as_mut_ptr(),
+                self.value_of(lhs),
+                self.value_of(rhs),
+            ),
+            "SCIPcreateConsBasicQuadratic",
+        )?;
+        self.constraints.push(cons);
+        modeling(SCIPaddCons(self.scip, cons), "SCIPaddCons")
+    }
+
+    fn value_of(&self, value: f64) -> f64 {
+        if value.is_infinite() {
+            value.signum() * unsafe { SCIPinfinity(self.scip) }
+        } else {
+            value
+        }
+    }
+
+    fn release(&mut self) -> Result<(), SolverError> {
+        unsafe {
+            for mut cons in self.constraints.drain(..) {
+                modeling(SCIPreleaseCons(self.scip, &mut cons), "SCIPreleaseCons")?;
+            }
+            for mut var in self.variables.drain(..) {
+                modeling(SCIPreleaseVar(self.scip, &mut var), "SCIPreleaseVar")?;
+            }
+            for mut var in self.auxiliary_variables.drain(..) {
+                modeling(SCIPreleaseVar(self.scip, &mut var), "SCIPreleaseVar")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Solver for ScipSolver {
+    fn name(&self) -> &'static str {
+        "scip"
+    }
+
+    fn solve(
+        &mut self,
+        model: &LinearMetaModel,
+        config: &SolverConfig,
+    ) -> Result<SolverOutput, SolverError> {
+        solve_encoded(model, |model| {
+            self.load(model)?;
+            self.configure(config)?;
+            ScipSolver::solve(self)?.into_result()
+        })
+    }
+
+    fn solve_quadratic(
+        &mut self,
+        model: &QuadraticMetaModel,
+        config: &SolverConfig,
+    ) -> Result<SolverOutput, SolverError> {
+        check_quadratic_encoding(model)?;
+        self.load_quadratic(model)?;
+        self.configure(config)?;
+        ScipSolver::solve(self)?.into_result()
+    }
+}
+
+impl Drop for ScipSolver {
+    fn drop(&mut self) {
+        if !self.scip.is_null() {
+            let _ = self.release();
+            unsafe {
+                SCIPfree(&mut self.scip);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_status_of() {
+    assert_eq!(
+        status_of(SCIP_Status_SCIP_STATUS_OPTIMAL),
+        SolverStatus::Optimal
+    );
+    assert_eq!(
+        status_of(SCIP_Status_SCIP_STATUS_GAPLIMIT),
+        SolverStatus::Feasible
+    );
+    assert_eq!(
+        status_of(SCIP_Status_SCIP_STATUS_INFEASIBLE),
+        SolverStatus::Infeasible
+    );
+    assert_eq!(
+        status_of(SCIP_Status_SCIP_STATUS_UNBOUNDED),
+        SolverStatus::Unbounded
+    );
+    assert_eq!(
+        status_of(SCIP_Status_SCIP_STATUS_INFORUNBD),
+        SolverStatus::InfeasibleOrUnbounded
+    );
+    assert_eq!(
+        status_of(SCIP_Status_SCIP_STATUS_TIMELIMIT),
+        SolverStatus::TimeLimit
+    );
+    assert_eq!(
+        status_of(SCIP_Status_SCIP_STATUS_STALLNODELIMIT),
+        SolverStatus::NodeLimit
+    );
+    assert_eq!(
+        status_of(SCIP_Status_SCIP_STATUS_USERINTERRUPT),
+        SolverStatus::Interrupted
+    );
+    assert_eq!(
+        status_of(SCIP_Status_SCIP_STATUS_UNKNOWN),
+        SolverStatus::Error
+    );
+}
+
+#[test]
+fn test_retcode() {
+    assert!(modeling(SCIP_Retcode_SCIP_OKAY, "SCIPcreateVarBasic").is_ok());
+    let err = solving(SCIP_Retcode_SCIP_NOMEMORY, "SCIPsolve").unwrap_err();
+    assert_eq!(err.code(), ErrorCode::OREngineSolvingException);
+    assert_eq!(err.what(), "SCIPsolve failed with SCIP_NOMEMORY (-1).");
+    assert_eq!(retcode_name(-100), "unknown SCIP_Retcode");
+}
@@ -0,0 +1,39 @@
+use ospf_rust_base::ErrorCode;
+use std::fmt;
+
+// the error of both the modeling frontend and the solver backends, re-exported there as
+// ModelError and SolverError
+#[derive(Clone)]
+pub struct Error {
+    code: ErrorCode,
+    msg: String,
+}
+
+impl Error {
+    pub fn new(code: ErrorCode, msg: String) -> Self {
+        Self {
+            code: code,
+            msg: msg,
+        }
+    }
+
+    pub fn code(&self) -> ErrorCode {
+        self.code
+    }
+
+    pub fn what(&self) -> &str {
+        &self.msg
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.code, self.msg)
+    }
+}
+
+impl fmt::Debug for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.code, self.msg)
+    }
+}
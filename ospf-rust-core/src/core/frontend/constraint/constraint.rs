@@ -0,0 +1,348 @@
+use super::super::expression::*;
+use super::super::model::ModelError;
+use ospf_rust_base::ErrorCode;
+use std::fmt;
+use std::fmt::Display;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Sign {
+    LessEqual,
+    GreaterEqual,
+    Equal,
+    Ranged(f64),
+}
+
+impl Sign {
+    pub fn reverse(&self) -> Self {
+        match self {
+            Sign::LessEqual => Sign::GreaterEqual,
+            Sign::GreaterEqual => Sign::LessEqual,
+            _ => *self,
+        }
+    }
+}
+
+impl Display for Sign {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Sign::LessEqual | Sign::Ranged(_) => write!(f, "<="),
+            Sign::GreaterEqual => write!(f, ">="),
+            Sign::Equal => write!(f, "=="),
+        }
+    }
+}
+
+fn check_range(lb: f64, ub: f64) -> Result<(), ModelError> {
+    if lb <= ub {
+        Ok(())
+    } else {
+        Err(ModelError::new(
+            ErrorCode::OREngineModelingException,
+            format!("Lower bound {} is greater than upper bound {}.", lb, ub),
+        ))
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Constraint {
+    pub lhs: LinearPolynomial,
+    pub sign: Sign,
+    pub rhs: LinearPolynomial,
+    pub name: String,
+}
+
+impl Constraint {
+    pub fn new<Lhs: Into<LinearPolynomial>, Rhs: Into<LinearPolynomial>>(
+        lhs: Lhs,
+        sign: Sign,
+        rhs: Rhs,
+    ) -> Self {
+        let lhs = lhs.into();
+        let rhs = rhs.into();
+        if lhs.is_constant() && !rhs.is_constant() {
+            Self {
+                lhs: rhs,
+                sign: sign.reverse(),
+                rhs: lhs,
+                name: String::new(),
+            }
+        } else {
+            Self {
+                lhs: lhs,
+                sign: sign,
+                rhs: rhs,
+                name: String::new(),
+            }
+        }
+    }
+
+    pub fn new_ranged<Expr: Into<LinearPolynomial>>(
+        lb: f64,
+        expr: Expr,
+        ub: f64,
+    ) -> Result<Self, ModelError> {
+        check_range(lb, ub)?;
+        Ok(Self::new(expr, Sign::Ranged(lb), ub))
+    }
+
+    pub fn with_name(mut self, name: &str) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    pub fn expression(&self) -> LinearPolynomial {
+        let mut ret = match self.sign {
+            Sign::Ranged(_) => self.lhs.clone(),
+            _ => self.lhs.clone() - self.rhs.clone(),
+        };
+        let constant = ret.constant();
+        ret.add_constant(-constant);
+        ret
+    }
+
+    fn offset(&self) -> f64 {
+        match self.sign {
+            Sign::Ranged(_) => self.lhs.constant(),
+            _ => self.lhs.constant() - self.rhs.constant(),
+        }
+    }
+
+    pub fn lower_bound(&self) -> f64 {
+        match self.sign {
+            Sign::LessEqual => f64::NEG_INFINITY,
+            Sign::GreaterEqual | Sign::Equal => -self.offset(),
+            Sign::Ranged(lb) => lb - self.offset(),
+        }
+    }
+
+    pub fn upper_bound(&self) -> f64 {
+        match self.sign {
+            Sign::GreaterEqual => f64::INFINITY,
+            Sign::LessEqual | Sign::Equal => -self.offset(),
+            Sign::Ranged(_) => self.rhs.constant() - self.offset(),
+        }
+    }
+
+    pub fn slack<F>(&self, value_of: F) -> Option<f64>
+    where
+        F: Fn(&VariableSymbol) -> Option<f64>,
+    {
+        let value = self.expression().evaluate(value_of)?;
+        Option::Some(f64::min(
+            value - self.lower_bound(),
+            self.upper_bound() - value,
+        ))
+    }
+
+    pub fn is_binding<F>(&self, value_of: F, tolerance: f64) -> bool
+    where
+        F: Fn(&VariableSymbol) -> Option<f64>,
+    {
+        match self.slack(value_of) {
+            Option::Some(slack) => slack.abs() <= tolerance,
+            Option::None => false,
+        }
+    }
+}
+
+impl Display for Constraint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !self.name.is_empty() {
+            write!(f, "{}: ", self.name)?;
+        }
+        match self.sign {
+            Sign::Ranged(lb) => write!(f, "{} <= {} <= {}", lb, self.lhs, self.rhs),
+            _ => write!(f, "{} {} {}", self.lhs, self.sign, self.rhs),
+        }
+    }
+}
+
+pub trait LinearComparison: Into<LinearPolynomial> + Sized {
+    fn leq<Rhs: Into<LinearPolynomial>>(self, rhs: Rhs) -> Constraint {
+        Constraint::new(self, Sign::LessEqual, rhs)
+    }
+
+    fn geq<Rhs: Into<LinearPolynomial>>(self, rhs: Rhs) -> Constraint {
+        Constraint::new(self, Sign::GreaterEqual, rhs)
+    }
+
+    fn equal<Rhs: Into<LinearPolynomial>>(self, rhs: Rhs) -> Constraint {
+        Constraint::new(self, Sign::Equal, rhs)
+    }
+
+    fn between(self, lb: f64, ub: f64) -> Result<Constraint, ModelError> {
+        Constraint::new_ranged(lb, self, ub)
+    }
+}
+
+impl<T: Into<LinearPolynomial>> LinearComparison for T {}
+
+#[derive(Clone, Debug)]
+pub struct QuadraticConstraint {
+    pub lhs: QuadraticPolynomial,
+    pub sign: Sign,
+    pub rhs: QuadraticPolynomial,
+    pub name: String,
+}
+
+impl QuadraticConstraint {
+    pub fn new<Lhs: Into<QuadraticPolynomial>, Rhs: Into<QuadraticPolynomial>>(
+        lhs: Lhs,
+        sign: Sign,
+        rhs: Rhs,
+    ) -> Self {
+        let lhs = lhs.into();
+        let rhs = rhs.into();
+        if lhs.is_constant() && !rhs.is_constant() {
+            Self {
+                lhs: rhs,
+                sign: sign.reverse(),
+                rhs: lhs,
+                name: String::new(),
+            }
+        } else {
+            Self {
+                lhs: lhs,
+                sign: sign,
+                rhs: rhs,
+                name: String::new(),
+            }
+        }
+    }
+
+    pub fn new_ranged<Expr: Into<QuadraticPolynomial>>(
+        lb: f64,
+        expr: Expr,
+        ub: f64,
+    ) -> Result<Self, ModelError> {
+        check_range(lb, ub)?;
+        Ok(Self::new(expr, Sign::Ranged(lb), ub))
+    }
+
+    pub fn with_name(mut self, name: &str) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    pub fn expression(&self) -> QuadraticPolynomial {
+        let mut ret = match self.sign {
+            Sign::Ranged(_) => self.lhs.clone(),
+            _ => self.lhs.clone() - self.rhs.clone(),
+        };
+        let constant = ret.constant();
+        ret.add_constant(-constant);
+        ret
+    }
+
+    fn offset(&self) -> f64 {
+        match self.sign {
+            Sign::Ranged(_) => self.lhs.constant(),
+            _ => self.lhs.constant() - self.rhs.constant(),
+        }
+    }
+
+    pub fn lower_bound(&self) -> f64 {
+        match self.sign {
+            Sign::LessEqual => f64::NEG_INFINITY,
+            Sign::GreaterEqual | Sign::Equal => -self.offset(),
+            Sign::Ranged(lb) => lb - self.offset(),
+        }
+    }
+
+    pub fn upper_bound(&self) -> f64 {
+        match self.sign {
+            Sign::GreaterEqual => f64::INFINITY,
+            Sign::LessEqual | Sign::Equal => -self.offset(),
+            Sign::Ranged(_) => self.rhs.constant() - self.offset(),
+        }
+    }
+}
+
+impl Display for QuadraticConstraint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !self.name.is_empty() {
+            write!(f, "{}: ", self.name)?;
+        }
+        match self.sign {
+            Sign::Ranged(lb) => write!(f, "{} <= {} <= {}", lb, self.lhs, self.rhs),
+            _ => write!(f, "{} {} {}", self.lhs, self.sign, self.rhs),
+        }
+    }
+}
+
+// only implemented by quadratic expressions, linear ones keep building linear constraints
+pub trait QuadraticComparison: Into<QuadraticPolynomial> + Sized {
+    fn leq<Rhs: Into<QuadraticPolynomial>>(self, rhs: Rhs) -> QuadraticConstraint {
+        QuadraticConstraint::new(self, Sign::LessEqual, rhs)
+    }
+
+    fn geq<Rhs: Into<QuadraticPolynomial>>(self, rhs: Rhs) -> QuadraticConstraint {
+        QuadraticConstraint::new(self, Sign::GreaterEqual, rhs)
+    }
+
+    fn equal<Rhs: Into<QuadraticPolynomial>>(self, rhs: Rhs) -> QuadraticConstraint {
+        QuadraticConstraint::new(self, Sign::Equal, rhs)
+    }
+
+    fn between(self, lb: f64, ub: f64) -> Result<QuadraticConstraint, ModelError> {
+        QuadraticConstraint::new_ranged(lb, self, ub)
+    }
+}
+
+impl QuadraticComparison for QuadraticMonomial {}
+impl QuadraticComparison for QuadraticPolynomial {}
+
+#[test]
+fn test_linear_comparison() {
+    use super::super::variable::*;
+
+    let x = RealVar::new_with_name("x");
+    let y = RealVar::new_with_name("y");
+
+    let constraint = (&x + 1.).leq(&y);
+    assert_eq!(constraint.sign, Sign::LessEqual);
+    assert_eq!(
+        (constraint.lower_bound(), constraint.upper_bound()),
+        (f64::NEG_INFINITY, -1.)
+    );
+    assert_eq!(format!("{}", constraint.expression()), "x - y");
+
+    // a constant left-hand side is moved to the right, reversing the sign
+    let constraint = Constraint::new(2., Sign::LessEqual, &x).with_name("c");
+    assert_eq!(constraint.sign, Sign::GreaterEqual);
+    assert_eq!(format!("{}", constraint), "c: x >= 2");
+    assert_eq!(
+        (constraint.lower_bound(), constraint.upper_bound()),
+        (2., f64::INFINITY)
+    );
+
+    let constraint = (&x).equal(3.);
+    assert_eq!(constraint.sign, Sign::Equal);
+    assert_eq!(
+        (constraint.lower_bound(), constraint.upper_bound()),
+        (3., 3.)
+    );
+}
+
+#[test]
+fn test_ranged_constraint() {
+    use super::super::variable::*;
+
+    let x = RealVar::new_with_name("x");
+
+    // the lower bound is kept in the sign, the upper one in the right-hand side
+    let constraint = (&x + 1.).between(-1., 4.).unwrap();
+    assert_eq!(constraint.sign, Sign::Ranged(-1.));
+    assert_eq!(format!("{}", constraint), "-1 <= x + 1 <= 4");
+    assert_eq!(
+        (constraint.lower_bound(), constraint.upper_bound()),
+        (-2., 3.)
+    );
+    assert_eq!(constraint.slack(|_| Option::Some(2.)), Option::Some(1.));
+
+    assert_eq!(
+        Constraint::new_ranged(2., &x, 1.).err().unwrap().code(),
+        ErrorCode::OREngineModelingException
+    );
+    assert!((&x * &x).between(1., 0.).is_err());
+}
@@ -0,0 +1,57 @@
+use super::super::expression::VariableSymbol;
+use super::super::variable::{Binary, VariableItem};
+use super::constraint::Constraint;
+use std::fmt;
+use std::fmt::Display;
+
+// the constraint is only enforced when the binary indicator takes the active value,
+// solvers without native support get a big-M reformulation from the variable bounds
+#[derive(Clone, Debug)]
+pub struct IndicatorConstraint {
+    pub indicator: VariableSymbol,
+    pub active_value: bool,
+    pub constraint: Constraint,
+    pub name: String,
+}
+
+impl IndicatorConstraint {
+    // if indicator == 1 then constraint
+    pub fn new<V: VariableItem<Type = Binary>>(indicator: &V, constraint: Constraint) -> Self {
+        Self {
+            indicator: VariableSymbol::from(indicator),
+            active_value: true,
+            name: constraint.name.clone(),
+            constraint: constraint,
+        }
+    }
+
+    // if indicator == 0 then constraint
+    pub fn new_negated<V: VariableItem<Type = Binary>>(
+        indicator: &V,
+        constraint: Constraint,
+    ) -> Self {
+        let mut ret = Self::new(indicator, constraint);
+        ret.active_value = false;
+        ret
+    }
+
+    pub fn with_name(mut self, name: &str) -> Self {
+        self.name = name.to_string();
+        self
+    }
+}
+
+impl Display for IndicatorConstraint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !self.name.is_empty() {
+            write!(f, "{}: ", self.name)?;
+        }
+        let mut constraint = self.constraint.clone();
+        constraint.name = String::new();
+        write!(
+            f,
+            "{} == {} -> {}",
+            self.indicator, self.active_value as u8, constraint
+        )
+    }
+}
@@ -0,0 +1,9 @@
+pub mod constraint;
+pub mod indicator_constraint;
+pub mod special_ordered_set;
+
+pub use constraint::{
+    Constraint, LinearComparison, QuadraticComparison, QuadraticConstraint, Sign,
+};
+pub use indicator_constraint::IndicatorConstraint;
+pub use special_ordered_set::{SosCategory, SpecialOrderedSet};
@@ -0,0 +1,136 @@
+use super::super::expression::VariableSymbol;
+use super::super::model::ModelError;
+use super::super::variable::VariableItem;
+use ospf_rust_base::ErrorCode;
+use std::fmt;
+use std::fmt::Display;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SosCategory {
+    // at most one member is nonzero
+    Sos1,
+    // at most two members are nonzero, and they are adjacent in the order of the weights
+    Sos2,
+}
+
+fn error(message: String) -> ModelError {
+    ModelError::new(ErrorCode::OREngineModelingException, message)
+}
+
+#[derive(Clone, Debug)]
+pub struct SpecialOrderedSet {
+    pub category: SosCategory,
+    pub symbols: Vec<VariableSymbol>,
+    pub weights: Vec<f64>,
+    pub name: String,
+}
+
+impl SpecialOrderedSet {
+    // members are weighted 1, 2, ... in the given order
+    pub fn new<'a, V: VariableItem + 'a, I: IntoIterator<Item = &'a V>>(
+        category: SosCategory,
+        items: I,
+    ) -> Self {
+        let symbols: Vec<VariableSymbol> = items.into_iter().map(VariableSymbol::from).collect();
+        let weights = (1..=symbols.len()).map(|weight| weight as f64).collect();
+        Self {
+            category: category,
+            symbols: symbols,
+            weights: weights,
+            name: String::new(),
+        }
+    }
+
+    pub fn new_sos1<'a, V: VariableItem + 'a, I: IntoIterator<Item = &'a V>>(items: I) -> Self {
+        Self::new(SosCategory::Sos1, items)
+    }
+
+    pub fn new_sos2<'a, V: VariableItem + 'a, I: IntoIterator<Item = &'a V>>(items: I) -> Self {
+        Self::new(SosCategory::Sos2, items)
+    }
+
+    // weights should be finite and distinct, so that they order the members
+    pub fn with_weights(mut self, weights: Vec<f64>) -> Result<Self, ModelError> {
+        if weights.len() != self.symbols.len() {
+            return Err(error(format!(
+                "{} weights are given for {} members.",
+                weights.len(),
+                self.symbols.len()
+            )));
+        }
+        for i in 0..weights.len() {
+            if !weights[i].is_finite() {
+                return Err(error(format!(
+                    "Member {} has a non-finite weight {}.",
+                    self.symbols[i], weights[i]
+                )));
+            }
+            for j in (i + 1)..weights.len() {
+                if weights[i] == weights[j] {
+                    return Err(error(format!(
+                        "Members {} and {} have the same weight {}.",
+                        self.symbols[i], self.symbols[j], weights[i]
+                    )));
+                }
+            }
+        }
+        self.weights = weights;
+        Ok(self)
+    }
+
+    pub fn with_name(mut self, name: &str) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    // members sorted by their weights
+    pub fn ordered_symbols(&self) -> Vec<&VariableSymbol> {
+        let mut members: Vec<(&VariableSymbol, f64)> = self
+            .symbols
+            .iter()
+            .zip(self.weights.iter().copied())
+            .collect();
+        members.sort_by(|lhs, rhs| lhs.1.total_cmp(&rhs.1));
+        members.into_iter().map(|(symbol, _)| symbol).collect()
+    }
+}
+
+impl Display for SpecialOrderedSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !self.name.is_empty() {
+            write!(f, "{}: ", self.name)?;
+        }
+        write!(f, "{:?}(", self.category)?;
+        for (i, (symbol, weight)) in self.symbols.iter().zip(self.weights.iter()).enumerate() {
+            if i != 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}: {}", symbol, weight)?;
+        }
+        write!(f, ")")
+    }
+}
+
+#[test]
+fn test_weights() {
+    use super::super::variable::*;
+
+    let x = RealVar::new_with_name("x");
+    let y = RealVar::new_with_name("y");
+    let set = SpecialOrderedSet::new_sos2([&x, &y])
+        .with_weights(vec![2., 1.])
+        .unwrap();
+    assert_eq!(
+        set.ordered_symbols(),
+        vec![&VariableSymbol::from(&y), &VariableSymbol::from(&x)]
+    );
+    assert!(SpecialOrderedSet::new_sos2([&x, &y])
+        .with_weights(vec![1.])
+        .is_err());
+    assert!(SpecialOrderedSet::new_sos2([&x, &y])
+        .with_weights(vec![1., 1.])
+        .is_err());
+    assert!(SpecialOrderedSet::new_sos2([&x, &y])
+        .with_weights(vec![1., f64::NAN])
+        .is_err());
+}
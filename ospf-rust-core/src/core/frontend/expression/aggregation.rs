@@ -0,0 +1,99 @@
+use super::super::model::ModelError;
+use super::super::variable::item::CombinedVariableItem;
+use super::super::variable::{VariableItem, VariableType};
+use super::monomial::*;
+use super::polynomial::*;
+use ospf_rust_base::ErrorCode;
+use ospf_rust_multiarray::{MultiArray, Shape};
+use std::iter::Sum;
+
+// sum(&x) over a whole combination, sum(x.select(dummy![.., j]).unwrap()) over a slice of it
+pub fn sum<'a, V: VariableItem + 'a, I: IntoIterator<Item = &'a V>>(items: I) -> LinearPolynomial {
+    items.into_iter().map(LinearMonomial::from).sum()
+}
+
+// items are weighted by the coefficients at their own vectors,
+// so the coefficients should have the shape of the combination the items belong to
+pub fn weighted_sum<'a, Type, const D: usize, S, I>(
+    coefficients: &MultiArray<f64, S>,
+    items: I,
+) -> Result<LinearPolynomial, ModelError>
+where
+    Type: VariableType + 'a,
+    S: Shape,
+    I: IntoIterator<Item = &'a CombinedVariableItem<Type, D>>,
+{
+    let mut ret = LinearPolynomial::new();
+    for item in items {
+        if item.shape() != coefficients.shape().shape() {
+            return Err(ModelError::new(
+                ErrorCode::OREngineModelingException,
+                format!(
+                    "Coefficients with shape {:?} cannot weight {} with shape {:?}.",
+                    coefficients.shape().shape(),
+                    item.name(),
+                    item.shape()
+                ),
+            ));
+        }
+        ret.add_monomial(LinearMonomial::new_with(coefficients[item.index()], item));
+    }
+    Ok(ret)
+}
+
+impl Sum<LinearMonomial> for LinearPolynomial {
+    fn sum<I: Iterator<Item = LinearMonomial>>(iter: I) -> Self {
+        let mut ret = LinearPolynomial::new();
+        for monomial in iter {
+            ret.add_monomial(monomial);
+        }
+        ret
+    }
+}
+
+impl Sum<LinearPolynomial> for LinearPolynomial {
+    fn sum<I: Iterator<Item = LinearPolynomial>>(iter: I) -> Self {
+        let mut ret = LinearPolynomial::new();
+        for polynomial in iter {
+            ret.add_polynomial(polynomial);
+        }
+        ret
+    }
+}
+
+impl Sum<QuadraticMonomial> for QuadraticPolynomial {
+    fn sum<I: Iterator<Item = QuadraticMonomial>>(iter: I) -> Self {
+        let mut ret = QuadraticPolynomial::new();
+        for monomial in iter {
+            ret.add_quadratic_monomial(monomial);
+        }
+        ret
+    }
+}
+
+impl Sum<QuadraticPolynomial> for QuadraticPolynomial {
+    fn sum<I: Iterator<Item = QuadraticPolynomial>>(iter: I) -> Self {
+        let mut ret = QuadraticPolynomial::new();
+        for polynomial in iter {
+            ret.add_polynomial(polynomial);
+        }
+        ret
+    }
+}
+
+#[test]
+fn test_slice_sum() {
+    use super::super::variable::*;
+    use ospf_rust_multiarray::{dummy, Shape2};
+
+    let x = BinVariable::<2>::new_with_shape("x", &[2, 3]);
+    let column = sum(x.select(dummy![.., 1]).unwrap());
+    assert_eq!(format!("{}", column), "x_0_1 + x_1_1");
+    assert_eq!(sum(&x).monomials().len(), 6);
+
+    let cost = MultiArray::new_by(Shape2::new([2, 3]), |index| index as f64);
+    let poly = weighted_sum(&cost, x.select(dummy![1, ..]).unwrap()).unwrap();
+    assert_eq!(format!("{}", poly), "3 * x_1_0 + 4 * x_1_1 + 5 * x_1_2");
+    let cost = MultiArray::new_by(Shape2::new([3, 2]), |index| index as f64);
+    assert!(weighted_sum(&cost, x.select(dummy![1, ..]).unwrap()).is_err());
+}
@@ -0,0 +1,8 @@
+pub mod aggregation;
+pub mod monomial;
+pub mod operator;
+pub mod polynomial;
+
+pub use aggregation::{sum, weighted_sum};
+pub use monomial::{LinearMonomial, QuadraticMonomial, VariableSymbol};
+pub use polynomial::{LinearPolynomial, QuadraticPolynomial};
@@ -0,0 +1,153 @@
+use super::super::variable::VariableItem;
+use std::fmt;
+use std::fmt::Display;
+use std::hash::*;
+
+#[derive(Clone, Debug)]
+pub struct VariableSymbol {
+    pub identifier: u64,
+    pub index: usize,
+    pub name: String,
+}
+
+impl VariableSymbol {
+    pub fn new(identifier: u64, index: usize, name: &str) -> Self {
+        Self {
+            identifier: identifier,
+            index: index,
+            name: name.to_string(),
+        }
+    }
+
+    pub fn key(&self) -> (u64, usize) {
+        (self.identifier, self.index)
+    }
+}
+
+impl<V: VariableItem> From<&V> for VariableSymbol {
+    fn from(item: &V) -> Self {
+        Self::new(item.identifier(), item.index(), item.name())
+    }
+}
+
+impl PartialEq for VariableSymbol {
+    fn eq(&self, rhs: &Self) -> bool {
+        self.key() == rhs.key()
+    }
+}
+
+impl Eq for VariableSymbol {}
+
+impl Hash for VariableSymbol {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.key().hash(state);
+    }
+}
+
+impl Display for VariableSymbol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.name.is_empty() {
+            write!(f, "x_{}_{}", self.identifier, self.index)
+        } else {
+            write!(f, "{}", self.name)
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct LinearMonomial {
+    pub coefficient: f64,
+    pub symbol: VariableSymbol,
+}
+
+impl LinearMonomial {
+    pub fn new(coefficient: f64, symbol: VariableSymbol) -> Self {
+        Self {
+            coefficient: coefficient,
+            symbol: symbol,
+        }
+    }
+
+    pub fn new_with<V: VariableItem>(coefficient: f64, item: &V) -> Self {
+        Self::new(coefficient, VariableSymbol::from(item))
+    }
+}
+
+impl<V: VariableItem> From<&V> for LinearMonomial {
+    fn from(item: &V) -> Self {
+        Self::new_with(1., item)
+    }
+}
+
+impl Display for LinearMonomial {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.coefficient == 1. {
+            write!(f, "{}", self.symbol)
+        } else if self.coefficient == -1. {
+            write!(f, "-{}", self.symbol)
+        } else {
+            write!(f, "{} * {}", self.coefficient, self.symbol)
+        }
+    }
+}
+
+// x * y and y * x are the same term, the key orders the symbols
+pub type QuadraticKey = ((u64, usize), (u64, usize));
+
+#[derive(Clone, Debug)]
+pub struct QuadraticMonomial {
+    pub coefficient: f64,
+    pub symbol1: VariableSymbol,
+    pub symbol2: VariableSymbol,
+}
+
+impl QuadraticMonomial {
+    pub fn new(coefficient: f64, symbol1: VariableSymbol, symbol2: VariableSymbol) -> Self {
+        Self {
+            coefficient: coefficient,
+            symbol1: symbol1,
+            symbol2: symbol2,
+        }
+    }
+
+    pub fn new_with<V1: VariableItem, V2: VariableItem>(
+        coefficient: f64,
+        item1: &V1,
+        item2: &V2,
+    ) -> Self {
+        Self::new(
+            coefficient,
+            VariableSymbol::from(item1),
+            VariableSymbol::from(item2),
+        )
+    }
+
+    pub fn key(&self) -> QuadraticKey {
+        let (key1, key2) = (self.symbol1.key(), self.symbol2.key());
+        if key1 <= key2 {
+            (key1, key2)
+        } else {
+            (key2, key1)
+        }
+    }
+
+    pub fn is_square(&self) -> bool {
+        self.symbol1 == self.symbol2
+    }
+}
+
+impl Display for QuadraticMonomial {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.coefficient == 1. {
+            write!(f, "{} * {}", self.symbol1, self.symbol2)
+        } else if self.coefficient == -1. {
+            write!(f, "-{} * {}", self.symbol1, self.symbol2)
+        } else {
+            write!(
+                f,
+                "{} * {} * {}",
+                self.coefficient, self.symbol1, self.symbol2
+            )
+        }
+    }
+}
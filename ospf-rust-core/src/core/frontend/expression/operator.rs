@@ -0,0 +1,216 @@
+use super::super::variable::item::*;
+use super::super::variable::{VariableItem, VariableType};
+use super::monomial::*;
+use super::polynomial::*;
+use std::ops::{Add, Mul, Neg, Sub};
+
+impl Neg for LinearMonomial {
+    type Output = LinearMonomial;
+
+    fn neg(mut self) -> Self::Output {
+        self.coefficient = -self.coefficient;
+        self
+    }
+}
+
+impl Mul<f64> for LinearMonomial {
+    type Output = LinearMonomial;
+
+    fn mul(mut self, rhs: f64) -> Self::Output {
+        self.coefficient *= rhs;
+        self
+    }
+}
+
+impl Mul<LinearMonomial> for f64 {
+    type Output = LinearMonomial;
+
+    fn mul(self, rhs: LinearMonomial) -> Self::Output {
+        rhs * self
+    }
+}
+
+impl<Rhs: Into<LinearPolynomial>> Add<Rhs> for LinearMonomial {
+    type Output = LinearPolynomial;
+
+    fn add(self, rhs: Rhs) -> Self::Output {
+        LinearPolynomial::from(self) + rhs
+    }
+}
+
+impl<Rhs: Into<LinearPolynomial>> Sub<Rhs> for LinearMonomial {
+    type Output = LinearPolynomial;
+
+    fn sub(self, rhs: Rhs) -> Self::Output {
+        LinearPolynomial::from(self) - rhs
+    }
+}
+
+impl Add<LinearMonomial> for f64 {
+    type Output = LinearPolynomial;
+
+    fn add(self, rhs: LinearMonomial) -> Self::Output {
+        rhs + self
+    }
+}
+
+impl Sub<LinearMonomial> for f64 {
+    type Output = LinearPolynomial;
+
+    fn sub(self, rhs: LinearMonomial) -> Self::Output {
+        -rhs + self
+    }
+}
+
+impl Mul<LinearMonomial> for LinearMonomial {
+    type Output = QuadraticMonomial;
+
+    fn mul(self, rhs: LinearMonomial) -> Self::Output {
+        QuadraticMonomial::new(self.coefficient * rhs.coefficient, self.symbol, rhs.symbol)
+    }
+}
+
+impl Neg for QuadraticMonomial {
+    type Output = QuadraticMonomial;
+
+    fn neg(mut self) -> Self::Output {
+        self.coefficient = -self.coefficient;
+        self
+    }
+}
+
+impl Mul<f64> for QuadraticMonomial {
+    type Output = QuadraticMonomial;
+
+    fn mul(mut self, rhs: f64) -> Self::Output {
+        self.coefficient *= rhs;
+        self
+    }
+}
+
+impl Mul<QuadraticMonomial> for f64 {
+    type Output = QuadraticMonomial;
+
+    fn mul(self, rhs: QuadraticMonomial) -> Self::Output {
+        rhs * self
+    }
+}
+
+impl<Rhs: Into<QuadraticPolynomial>> Add<Rhs> for QuadraticMonomial {
+    type Output = QuadraticPolynomial;
+
+    fn add(self, rhs: Rhs) -> Self::Output {
+        QuadraticPolynomial::from(self) + rhs
+    }
+}
+
+impl<Rhs: Into<QuadraticPolynomial>> Sub<Rhs> for QuadraticMonomial {
+    type Output = QuadraticPolynomial;
+
+    fn sub(self, rhs: Rhs) -> Self::Output {
+        QuadraticPolynomial::from(self) - rhs
+    }
+}
+
+macro_rules! variable_item_operator_template {
+    ($item:ty, $($generics:tt)*) => {
+        impl<$($generics)*> Neg for &$item {
+            type Output = LinearMonomial;
+
+            fn neg(self) -> Self::Output {
+                LinearMonomial::new_with(-1., self)
+            }
+        }
+
+        impl<$($generics)*> Mul<f64> for &$item {
+            type Output = LinearMonomial;
+
+            fn mul(self, rhs: f64) -> Self::Output {
+                LinearMonomial::new_with(rhs, self)
+            }
+        }
+
+        impl<$($generics)*> Mul<&$item> for f64 {
+            type Output = LinearMonomial;
+
+            fn mul(self, rhs: &$item) -> Self::Output {
+                LinearMonomial::new_with(self, rhs)
+            }
+        }
+
+        impl<$($generics)*, Rhs: Into<LinearPolynomial>> Add<Rhs> for &$item {
+            type Output = LinearPolynomial;
+
+            fn add(self, rhs: Rhs) -> Self::Output {
+                LinearPolynomial::from(self) + rhs
+            }
+        }
+
+        impl<$($generics)*, Rhs: Into<LinearPolynomial>> Sub<Rhs> for &$item {
+            type Output = LinearPolynomial;
+
+            fn sub(self, rhs: Rhs) -> Self::Output {
+                LinearPolynomial::from(self) - rhs
+            }
+        }
+
+        impl<$($generics)*, Rhs: VariableItem> Mul<&Rhs> for &$item {
+            type Output = QuadraticMonomial;
+
+            fn mul(self, rhs: &Rhs) -> Self::Output {
+                QuadraticMonomial::new_with(1., self, rhs)
+            }
+        }
+
+        impl<$($generics)*> Add<QuadraticMonomial> for &$item {
+            type Output = QuadraticPolynomial;
+
+            fn add(self, rhs: QuadraticMonomial) -> Self::Output {
+                QuadraticPolynomial::from(self) + rhs
+            }
+        }
+
+        impl<$($generics)*> Sub<QuadraticMonomial> for &$item {
+            type Output = QuadraticPolynomial;
+
+            fn sub(self, rhs: QuadraticMonomial) -> Self::Output {
+                QuadraticPolynomial::from(self) - rhs
+            }
+        }
+
+        impl<$($generics)*> Add<QuadraticPolynomial> for &$item {
+            type Output = QuadraticPolynomial;
+
+            fn add(self, rhs: QuadraticPolynomial) -> Self::Output {
+                QuadraticPolynomial::from(self) + rhs
+            }
+        }
+
+        impl<$($generics)*> Sub<QuadraticPolynomial> for &$item {
+            type Output = QuadraticPolynomial;
+
+            fn sub(self, rhs: QuadraticPolynomial) -> Self::Output {
+                QuadraticPolynomial::from(self) - rhs
+            }
+        }
+
+        impl<$($generics)*> Add<&$item> for f64 {
+            type Output = LinearPolynomial;
+
+            fn add(self, rhs: &$item) -> Self::Output {
+                rhs + self
+            }
+        }
+
+        impl<$($generics)*> Sub<&$item> for f64 {
+            type Output = LinearPolynomial;
+
+            fn sub(self, rhs: &$item) -> Self::Output {
+                -rhs + self
+            }
+        }
+    };
+}
+
+variable_item_operator_template!(IndependentVariableItem<Type>, Type: VariableType);
+variable_item_operator_template!(CombinedVariableItem<Type, D>, Type: VariableType, const D: usize);
@@ -0,0 +1,611 @@
+use super::monomial::*;
+use std::collections::HashMap;
+use std::fmt;
+use std::fmt::Display;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+#[derive(Clone, Debug)]
+pub struct LinearPolynomial {
+    _monomials: Vec<LinearMonomial>,
+    _indexes: HashMap<(u64, usize), usize>,
+    _constant: f64,
+}
+
+impl LinearPolynomial {
+    pub fn new() -> Self {
+        Self::new_with_constant(0.)
+    }
+
+    pub fn new_with_constant(constant: f64) -> Self {
+        Self {
+            _monomials: Vec::new(),
+            _indexes: HashMap::new(),
+            _constant: constant,
+        }
+    }
+
+    pub fn monomials(&self) -> &[LinearMonomial] {
+        &self._monomials
+    }
+
+    pub fn constant(&self) -> f64 {
+        self._constant
+    }
+
+    pub fn is_constant(&self) -> bool {
+        self._monomials.is_empty()
+    }
+
+    pub fn coefficient_of(&self, symbol: &VariableSymbol) -> f64 {
+        match self._indexes.get(&symbol.key()) {
+            Option::Some(index) => self._monomials[*index].coefficient,
+            Option::None => 0.,
+        }
+    }
+
+    pub fn add_monomial(&mut self, monomial: LinearMonomial) {
+        let key = monomial.symbol.key();
+        match self._indexes.get(&key) {
+            Option::Some(index) => {
+                let index = *index;
+                self._monomials[index].coefficient += monomial.coefficient;
+                if self._monomials[index].coefficient == 0. {
+                    self.remove_at(index);
+                }
+            }
+            Option::None => {
+                if monomial.coefficient != 0. {
+                    self._indexes.insert(key, self._monomials.len());
+                    self._monomials.push(monomial);
+                }
+            }
+        }
+    }
+
+    pub fn add_constant(&mut self, constant: f64) {
+        self._constant += constant;
+    }
+
+    pub fn add_polynomial(&mut self, polynomial: LinearPolynomial) {
+        for monomial in polynomial._monomials {
+            self.add_monomial(monomial);
+        }
+        self._constant += polynomial._constant;
+    }
+
+    pub fn scale(&mut self, factor: f64) {
+        if factor == 0. {
+            self._monomials.clear();
+            self._indexes.clear();
+        } else {
+            for monomial in self._monomials.iter_mut() {
+                monomial.coefficient *= factor;
+            }
+        }
+        self._constant *= factor;
+    }
+
+    pub fn evaluate<F>(&self, value_of: F) -> Option<f64>
+    where
+        F: Fn(&VariableSymbol) -> Option<f64>,
+    {
+        let mut ret = self._constant;
+        for monomial in &self._monomials {
+            ret += monomial.coefficient * value_of(&monomial.symbol)?;
+        }
+        Option::Some(ret)
+    }
+
+    fn remove_at(&mut self, index: usize) {
+        let removed = self._monomials.swap_remove(index);
+        self._indexes.remove(&removed.symbol.key());
+        if index < self._monomials.len() {
+            self._indexes
+                .insert(self._monomials[index].symbol.key(), index);
+        }
+    }
+}
+
+impl From<f64> for LinearPolynomial {
+    fn from(constant: f64) -> Self {
+        Self::new_with_constant(constant)
+    }
+}
+
+impl From<LinearMonomial> for LinearPolynomial {
+    fn from(monomial: LinearMonomial) -> Self {
+        let mut ret = Self::new();
+        ret.add_monomial(monomial);
+        ret
+    }
+}
+
+impl<V: super::super::variable::VariableItem> From<&V> for LinearPolynomial {
+    fn from(item: &V) -> Self {
+        Self::from(LinearMonomial::from(item))
+    }
+}
+
+impl Display for LinearPolynomial {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self._monomials.is_empty() {
+            return write!(f, "{}", self._constant);
+        }
+        for (i, monomial) in self._monomials.iter().enumerate() {
+            if i == 0 {
+                write!(f, "{}", monomial)?;
+            } else if monomial.coefficient < 0. {
+                write!(f, " - {}", -monomial.clone())?;
+            } else {
+                write!(f, " + {}", monomial)?;
+            }
+        }
+        if self._constant > 0. {
+            write!(f, " + {}", self._constant)?;
+        } else if self._constant < 0. {
+            write!(f, " - {}", -self._constant)?;
+        }
+        Ok(())
+    }
+}
+
+impl Neg for LinearPolynomial {
+    type Output = LinearPolynomial;
+
+    fn neg(mut self) -> Self::Output {
+        self.scale(-1.);
+        self
+    }
+}
+
+impl<Rhs: Into<LinearPolynomial>> AddAssign<Rhs> for LinearPolynomial {
+    fn add_assign(&mut self, rhs: Rhs) {
+        self.add_polynomial(rhs.into());
+    }
+}
+
+impl<Rhs: Into<LinearPolynomial>> SubAssign<Rhs> for LinearPolynomial {
+    fn sub_assign(&mut self, rhs: Rhs) {
+        self.add_polynomial(-rhs.into());
+    }
+}
+
+impl MulAssign<f64> for LinearPolynomial {
+    fn mul_assign(&mut self, rhs: f64) {
+        self.scale(rhs);
+    }
+}
+
+impl DivAssign<f64> for LinearPolynomial {
+    fn div_assign(&mut self, rhs: f64) {
+        self.scale(1. / rhs);
+    }
+}
+
+impl<Rhs: Into<LinearPolynomial>> Add<Rhs> for LinearPolynomial {
+    type Output = LinearPolynomial;
+
+    fn add(mut self, rhs: Rhs) -> Self::Output {
+        self += rhs;
+        self
+    }
+}
+
+impl<Rhs: Into<LinearPolynomial>> Sub<Rhs> for LinearPolynomial {
+    type Output = LinearPolynomial;
+
+    fn sub(mut self, rhs: Rhs) -> Self::Output {
+        self -= rhs;
+        self
+    }
+}
+
+impl Mul<f64> for LinearPolynomial {
+    type Output = LinearPolynomial;
+
+    fn mul(mut self, rhs: f64) -> Self::Output {
+        self *= rhs;
+        self
+    }
+}
+
+impl Div<f64> for LinearPolynomial {
+    type Output = LinearPolynomial;
+
+    fn div(mut self, rhs: f64) -> Self::Output {
+        self /= rhs;
+        self
+    }
+}
+
+impl Add<LinearPolynomial> for f64 {
+    type Output = LinearPolynomial;
+
+    fn add(self, rhs: LinearPolynomial) -> Self::Output {
+        rhs + self
+    }
+}
+
+impl Sub<LinearPolynomial> for f64 {
+    type Output = LinearPolynomial;
+
+    fn sub(self, rhs: LinearPolynomial) -> Self::Output {
+        -rhs + self
+    }
+}
+
+impl Mul<LinearPolynomial> for f64 {
+    type Output = LinearPolynomial;
+
+    fn mul(self, rhs: LinearPolynomial) -> Self::Output {
+        rhs * self
+    }
+}
+
+impl Mul<LinearPolynomial> for LinearPolynomial {
+    type Output = QuadraticPolynomial;
+
+    fn mul(self, rhs: LinearPolynomial) -> Self::Output {
+        let mut ret = QuadraticPolynomial::new();
+        for lhs_monomial in &self._monomials {
+            for rhs_monomial in &rhs._monomials {
+                ret.add_quadratic_monomial(lhs_monomial.clone() * rhs_monomial.clone());
+            }
+        }
+        let (lhs_constant, mut rhs) = (self._constant, rhs);
+        ret.add_linear(self * rhs._constant);
+        rhs._constant = 0.;
+        ret.add_linear(rhs * lhs_constant);
+        ret
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct QuadraticPolynomial {
+    _monomials: Vec<QuadraticMonomial>,
+    _indexes: HashMap<QuadraticKey, usize>,
+    _linear: LinearPolynomial,
+}
+
+impl QuadraticPolynomial {
+    pub fn new() -> Self {
+        Self::new_with_constant(0.)
+    }
+
+    pub fn new_with_constant(constant: f64) -> Self {
+        Self {
+            _monomials: Vec::new(),
+            _indexes: HashMap::new(),
+            _linear: LinearPolynomial::new_with_constant(constant),
+        }
+    }
+
+    pub fn monomials(&self) -> &[QuadraticMonomial] {
+        &self._monomials
+    }
+
+    // linear terms and the constant
+    pub fn linear(&self) -> &LinearPolynomial {
+        &self._linear
+    }
+
+    pub fn constant(&self) -> f64 {
+        self._linear.constant()
+    }
+
+    pub fn is_linear(&self) -> bool {
+        self._monomials.is_empty()
+    }
+
+    pub fn is_constant(&self) -> bool {
+        self.is_linear() && self._linear.is_constant()
+    }
+
+    pub fn coefficient_of(&self, symbol1: &VariableSymbol, symbol2: &VariableSymbol) -> f64 {
+        let key = QuadraticMonomial::new(0., symbol1.clone(), symbol2.clone()).key();
+        match self._indexes.get(&key) {
+            Option::Some(index) => self._monomials[*index].coefficient,
+            Option::None => 0.,
+        }
+    }
+
+    pub fn add_quadratic_monomial(&mut self, monomial: QuadraticMonomial) {
+        let key = monomial.key();
+        match self._indexes.get(&key) {
+            Option::Some(index) => {
+                let index = *index;
+                self._monomials[index].coefficient += monomial.coefficient;
+                if self._monomials[index].coefficient == 0. {
+                    self.remove_at(index);
+                }
+            }
+            Option::None => {
+                if monomial.coefficient != 0. {
+                    self._indexes.insert(key, self._monomials.len());
+                    self._monomials.push(monomial);
+                }
+            }
+        }
+    }
+
+    pub fn add_monomial(&mut self, monomial: LinearMonomial) {
+        self._linear.add_monomial(monomial);
+    }
+
+    pub fn add_linear(&mut self, polynomial: LinearPolynomial) {
+        self._linear.add_polynomial(polynomial);
+    }
+
+    pub fn add_constant(&mut self, constant: f64) {
+        self._linear.add_constant(constant);
+    }
+
+    pub fn add_polynomial(&mut self, polynomial: QuadraticPolynomial) {
+        for monomial in polynomial._monomials {
+            self.add_quadratic_monomial(monomial);
+        }
+        self._linear.add_polynomial(polynomial._linear);
+    }
+
+    pub fn scale(&mut self, factor: f64) {
+        if factor == 0. {
+            self._monomials.clear();
+            self._indexes.clear();
+        } else {
+            for monomial in self._monomials.iter_mut() {
+                monomial.coefficient *= factor;
+            }
+        }
+        self._linear.scale(factor);
+    }
+
+    pub fn evaluate<F>(&self, value_of: F) -> Option<f64>
+    where
+        F: Fn(&VariableSymbol) -> Option<f64>,
+    {
+        let mut ret = self._linear.evaluate(&value_of)?;
+        for monomial in &self._monomials {
+            ret +=
+                monomial.coefficient * value_of(&monomial.symbol1)? * value_of(&monomial.symbol2)?;
+        }
+        Option::Some(ret)
+    }
+
+    fn remove_at(&mut self, index: usize) {
+        let removed = self._monomials.swap_remove(index);
+        self._indexes.remove(&removed.key());
+        if index < self._monomials.len() {
+            self._indexes.insert(self._monomials[index].key(), index);
+        }
+    }
+}
+
+impl From<f64> for QuadraticPolynomial {
+    fn from(constant: f64) -> Self {
+        Self::new_with_constant(constant)
+    }
+}
+
+impl From<LinearMonomial> for QuadraticPolynomial {
+    fn from(monomial: LinearMonomial) -> Self {
+        Self::from(LinearPolynomial::from(monomial))
+    }
+}
+
+impl From<LinearPolynomial> for QuadraticPolynomial {
+    fn from(polynomial: LinearPolynomial) -> Self {
+        Self {
+            _monomials: Vec::new(),
+            _indexes: HashMap::new(),
+            _linear: polynomial,
+        }
+    }
+}
+
+impl From<QuadraticMonomial> for QuadraticPolynomial {
+    fn from(monomial: QuadraticMonomial) -> Self {
+        let mut ret = Self::new();
+        ret.add_quadratic_monomial(monomial);
+        ret
+    }
+}
+
+impl<V: super::super::variable::VariableItem> From<&V> for QuadraticPolynomial {
+    fn from(item: &V) -> Self {
+        Self::from(LinearMonomial::from(item))
+    }
+}
+
+impl Display for QuadraticPolynomial {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self._monomials.is_empty() {
+            return write!(f, "{}", self._linear);
+        }
+        for (i, monomial) in self._monomials.iter().enumerate() {
+            if i == 0 {
+                write!(f, "{}", monomial)?;
+            } else if monomial.coefficient < 0. {
+                write!(f, " - {}", -monomial.clone())?;
+            } else {
+                write!(f, " + {}", monomial)?;
+            }
+        }
+        for monomial in self._linear.monomials() {
+            if monomial.coefficient < 0. {
+                write!(f, " - {}", -monomial.clone())?;
+            } else {
+                write!(f, " + {}", monomial)?;
+            }
+        }
+        if self.constant() > 0. {
+            write!(f, " + {}", self.constant())?;
+        } else if self.constant() < 0. {
+            write!(f, " - {}", -self.constant())?;
+        }
+        Ok(())
+    }
+}
+
+impl Neg for QuadraticPolynomial {
+    type Output = QuadraticPolynomial;
+
+    fn neg(mut self) -> Self::Output {
+        self.scale(-1.);
+        self
+    }
+}
+
+impl<Rhs: Into<QuadraticPolynomial>> AddAssign<Rhs> for QuadraticPolynomial {
+    fn add_assign(&mut self, rhs: Rhs) {
+        self.add_polynomial(rhs.into());
+    }
+}
+
+impl<Rhs: Into<QuadraticPolynomial>> SubAssign<Rhs> for QuadraticPolynomial {
+    fn sub_assign(&mut self, rhs: Rhs) {
+        self.add_polynomial(-rhs.into());
+    }
+}
+
+impl MulAssign<f64> for QuadraticPolynomial {
+    fn mul_assign(&mut self, rhs: f64) {
+        self.scale(rhs);
+    }
+}
+
+impl DivAssign<f64> for QuadraticPolynomial {
+    fn div_assign(&mut self, rhs: f64) {
+        self.scale(1. / rhs);
+    }
+}
+
+impl<Rhs: Into<QuadraticPolynomial>> Add<Rhs> for QuadraticPolynomial {
+    type Output = QuadraticPolynomial;
+
+    fn add(mut self, rhs: Rhs) -> Self::Output {
+        self += rhs;
+        self
+    }
+}
+
+impl<Rhs: Into<QuadraticPolynomial>> Sub<Rhs> for QuadraticPolynomial {
+    type Output = QuadraticPolynomial;
+
+    fn sub(mut self, rhs: Rhs) -> Self::Output {
+        self -= rhs;
+        self
+    }
+}
+
+impl Mul<f64> for QuadraticPolynomial {
+    type Output = QuadraticPolynomial;
+
+    fn mul(mut self, rhs: f64) -> Self::Output {
+        self *= rhs;
+        self
+    }
+}
+
+impl Div<f64> for QuadraticPolynomial {
+    type Output = QuadraticPolynomial;
+
+    fn div(mut self, rhs: f64) -> Self::Output {
+        self /= rhs;
+        self
+    }
+}
+
+impl Add<QuadraticPolynomial> for f64 {
+    type Output = QuadraticPolynomial;
+
+    fn add(self, rhs: QuadraticPolynomial) -> Self::Output {
+        rhs + self
+    }
+}
+
+impl Sub<QuadraticPolynomial> for f64 {
+    type Output = QuadraticPolynomial;
+
+    fn sub(self, rhs: QuadraticPolynomial) -> Self::Output {
+        -rhs + self
+    }
+}
+
+impl Mul<QuadraticPolynomial> for f64 {
+    type Output = QuadraticPolynomial;
+
+    fn mul(self, rhs: QuadraticPolynomial) -> Self::Output {
+        rhs * self
+    }
+}
+
+// linear expressions absorb quadratic ones on the right side
+macro_rules! linear_quadratic_operator_template {
+    ($lhs:ty, $rhs:ty) => {
+        impl Add<$rhs> for $lhs {
+            type Output = QuadraticPolynomial;
+
+            fn add(self, rhs: $rhs) -> Self::Output {
+                QuadraticPolynomial::from(self) + rhs
+            }
+        }
+
+        impl Sub<$rhs> for $lhs {
+            type Output = QuadraticPolynomial;
+
+            fn sub(self, rhs: $rhs) -> Self::Output {
+                QuadraticPolynomial::from(self) - rhs
+            }
+        }
+    };
+}
+
+linear_quadratic_operator_template!(f64, QuadraticMonomial);
+linear_quadratic_operator_template!(LinearMonomial, QuadraticMonomial);
+linear_quadratic_operator_template!(LinearMonomial, QuadraticPolynomial);
+linear_quadratic_operator_template!(LinearPolynomial, QuadraticMonomial);
+linear_quadratic_operator_template!(LinearPolynomial, QuadraticPolynomial);
+
+#[test]
+fn test_linear_polynomial_merge() {
+    use super::super::variable::*;
+
+    let x = IntVar::new_with_name("x");
+    let y = RealVar::new_with_name("y");
+    let poly = 3. * &x + 2. * &y - 5. + &x - 2. * &y;
+    assert_eq!(poly.monomials().len(), 1);
+    assert_eq!(poly.coefficient_of(&VariableSymbol::from(&x)), 4.);
+    assert_eq!(poly.coefficient_of(&VariableSymbol::from(&y)), 0.);
+    assert_eq!(poly.constant(), -5.);
+}
+
+#[test]
+fn test_linear_polynomial_display() {
+    use super::super::variable::*;
+
+    let x = IntVar::new_with_name("x");
+    let y = RealVar::new_with_name("y");
+    let poly = 3. * &x - &y - 5.;
+    assert_eq!(format!("{}", poly), "3 * x - y - 5");
+    assert_eq!(format!("{}", -poly * 2.), "-6 * x + 2 * y + 10");
+}
+
+#[test]
+fn test_quadratic_polynomial() {
+    use super::super::variable::*;
+
+    let x = RealVar::new_with_name("x");
+    let y = RealVar::new_with_name("y");
+    let poly = (&x + 1.) * (&y - 2.) + &y * &x;
+    let (sx, sy) = (VariableSymbol::from(&x), VariableSymbol::from(&y));
+    assert_eq!(poly.monomials().len(), 1);
+    assert_eq!(poly.coefficient_of(&sy, &sx), 2.);
+    assert_eq!(poly.linear().coefficient_of(&sx), -2.);
+    assert_eq!(poly.linear().coefficient_of(&sy), 1.);
+    assert_eq!(poly.constant(), -2.);
+    assert_eq!(format!("{}", 3. * &x - &x * &x), "-x * x + 3 * x");
+    assert_eq!(
+        poly.evaluate(|symbol| Option::Some(if *symbol == sx { 2. } else { 3. })),
+        Option::Some(9.)
+    );
+}
@@ -0,0 +1,666 @@
+use super::super::constraint::{Constraint, Sign, SosCategory, SpecialOrderedSet};
+use super::super::expression::*;
+use super::super::model::{LinearMetaModel, ModelError, ObjectCategory};
+use super::super::variable::VariableCategory;
+use super::model_builder::*;
+use std::io::BufRead;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum Section {
+    Objective,
+    Constraints,
+    Bounds,
+    Generals,
+    Binaries,
+    SemiContinuous,
+    Sos,
+}
+
+fn section_of(keyword: &str) -> Option<(Section, Option<ObjectCategory>)> {
+    let keyword = keyword.split_whitespace().collect::<Vec<&str>>().join(" ");
+    match keyword.to_lowercase().as_str() {
+        "minimize" | "minimum" | "min" => {
+            Option::Some((Section::Objective, Option::Some(ObjectCategory::Minimum)))
+        }
+        "maximize" | "maximum" | "max" => {
+            Option::Some((Section::Objective, Option::Some(ObjectCategory::Maximum)))
+        }
+        "subject to" | "such that" | "st" | "s.t." | "st." => {
+            Option::Some((Section::Constraints, Option::None))
+        }
+        "bounds" | "bound" => Option::Some((Section::Bounds, Option::None)),
+        "generals" | "general" | "gen" | "integers" => {
+            Option::Some((Section::Generals, Option::None))
+        }
+        "binaries" | "binary" | "bin" => Option::Some((Section::Binaries, Option::None)),
+        "semi-continuous" | "semis" | "semi" => {
+            Option::Some((Section::SemiContinuous, Option::None))
+        }
+        "sos" => Option::Some((Section::Sos, Option::None)),
+        _ => Option::None,
+    }
+}
+
+#[derive(Clone, PartialEq, Debug)]
+enum Token {
+    Number(f64),
+    Name(String),
+    // + - * ^ [ ] / : :: <= >= = ->
+    Operator(&'static str),
+}
+
+fn is_name_char(c: char) -> bool {
+    c.is_alphanumeric() || "!\"#$%&(),.;?@_`'{}|~".contains(c)
+}
+
+fn tokenize(line: usize, text: &str, tokens: &mut Vec<(usize, Token)>) -> Result<(), ModelError> {
+    let chars: Vec<char> = text.chars().collect();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        let next = chars.get(i + 1).copied();
+        if c.is_whitespace() {
+            i += 1;
+            continue;
+        }
+        if c.is_ascii_digit() || (c == '.' && next.is_some_and(|c| c.is_ascii_digit())) {
+            let begin = i;
+            while i < chars.len() && (chars[i].is_ascii_digit() || chars[i] == '.') {
+                i += 1;
+            }
+            if i < chars.len() && (chars[i] == 'e' || chars[i] == 'E') {
+                let mut j = i + 1;
+                if j < chars.len() && (chars[j] == '+' || chars[j] == '-') {
+                    j += 1;
+                }
+                if j < chars.len() && chars[j].is_ascii_digit() {
+                    i = j;
+                    while i < chars.len() && chars[i].is_ascii_digit() {
+                        i += 1;
+                    }
+                }
+            }
+            let text: String = chars[begin..i].iter().collect();
+            match text.parse::<f64>() {
+                Ok(value) => tokens.push((line, Token::Number(value))),
+                Err(_) => return Err(error(line, format!("{} is not a number.", text))),
+            }
+            continue;
+        }
+        if is_name_char(c) {
+            let begin = i;
+            while i < chars.len() && is_name_char(chars[i]) {
+                i += 1;
+            }
+            tokens.push((line, Token::Name(chars[begin..i].iter().collect())));
+            continue;
+        }
+        let (operator, length) = match (c, next) {
+            ('<', Option::Some('=')) | ('=', Option::Some('<')) => ("<=", 2),
+            ('>', Option::Some('=')) | ('=', Option::Some('>')) => (">=", 2),
+            ('-', Option::Some('>')) => ("->", 2),
+            (':', Option::Some(':')) => ("::", 2),
+            ('<', _) => ("<=", 1),
+            ('>', _) => (">=", 1),
+            ('=', _) => ("=", 1),
+            ('+', _) => ("+", 1),
+            ('-', _) => ("-", 1),
+            ('*', _) => ("*", 1),
+            ('^', _) => ("^", 1),
+            ('[', _) => ("[", 1),
+            (']', _) => ("]", 1),
+            ('/', _) => ("/", 1),
+            (':', _) => (":", 1),
+            _ => return Err(error(line, format!("Unexpected character {}.", c))),
+        };
+        tokens.push((line, Token::Operator(operator)));
+        i += length;
+    }
+    Ok(())
+}
+
+fn sign_of(operator: &str) -> Option<Sign> {
+    match operator {
+        "<=" => Option::Some(Sign::LessEqual),
+        ">=" => Option::Some(Sign::GreaterEqual),
+        "=" => Option::Some(Sign::Equal),
+        _ => Option::None,
+    }
+}
+
+// the tokens of a section, read from the front
+struct TokenStream<'a> {
+    tokens: &'a [(usize, Token)],
+    position: usize,
+    // for errors at the end of the section
+    last_line: usize,
+}
+
+impl<'a> TokenStream<'a> {
+    fn new(tokens: &'a [(usize, Token)], last_line: usize) -> Self {
+        Self {
+            tokens: tokens,
+            position: 0,
+            last_line: last_line,
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.position >= self.tokens.len()
+    }
+
+    fn peek(&self, offset: usize) -> Option<&'a Token> {
+        self.tokens
+            .get(self.position + offset)
+            .map(|(_, token)| token)
+    }
+
+    fn line(&self) -> usize {
+        self.tokens
+            .get(self.position)
+            .map_or(self.last_line, |(line, _)| *line)
+    }
+
+    fn next(&mut self) -> Result<&'a Token, ModelError> {
+        match self.peek(0) {
+            Option::Some(token) => {
+                self.position += 1;
+                Ok(token)
+            }
+            Option::None => Err(error(
+                self.last_line,
+                String::from("Unexpected end of section."),
+            )),
+        }
+    }
+
+    fn is_operator(&self, offset: usize, operator: &str) -> bool {
+        matches!(self.peek(offset), Option::Some(Token::Operator(found)) if *found == operator)
+    }
+
+    fn is_sense(&self, offset: usize) -> bool {
+        matches!(self.peek(offset), Option::Some(Token::Operator(operator)) if sign_of(operator).is_some())
+    }
+
+    fn is_name(&self, offset: usize) -> bool {
+        matches!(self.peek(offset), Option::Some(Token::Name(_)))
+    }
+
+    fn is_number(&self, offset: usize) -> bool {
+        matches!(self.peek(offset), Option::Some(Token::Number(_)))
+    }
+
+    fn is_infinity(&self, offset: usize) -> bool {
+        match self.peek(offset) {
+            Option::Some(Token::Name(name)) => {
+                let name = name.to_lowercase();
+                name == "inf" || name == "infinity"
+            }
+            _ => false,
+        }
+    }
+
+    fn name(&mut self) -> Result<&'a str, ModelError> {
+        let line = self.line();
+        match self.next()? {
+            Token::Name(name) => Ok(name),
+            token => Err(error(line, format!("Expected a name, found {:?}.", token))),
+        }
+    }
+
+    fn operator(&mut self, operator: &str) -> Result<(), ModelError> {
+        let line = self.line();
+        match self.next()? {
+            Token::Operator(found) if *found == operator => Ok(()),
+            token => Err(error(
+                line,
+                format!("Expected {}, found {:?}.", operator, token),
+            )),
+        }
+    }
+
+    fn sense(&mut self) -> Result<Sign, ModelError> {
+        let line = self.line();
+        match self.next()? {
+            Token::Operator(operator) if sign_of(operator).is_some() => {
+                Ok(sign_of(operator).unwrap())
+            }
+            token => Err(error(line, format!("Expected a sense, found {:?}.", token))),
+        }
+    }
+
+    // a number with an optional sign, infinity included
+    fn number(&mut self) -> Result<f64, ModelError> {
+        let line = self.line();
+        let mut factor = 1.;
+        if self.is_operator(0, "+") || self.is_operator(0, "-") {
+            if self.is_operator(0, "-") {
+                factor = -1.;
+            }
+            self.position += 1;
+        }
+        if self.is_infinity(0) {
+            self.position += 1;
+            return Ok(factor * f64::INFINITY);
+        }
+        match self.next()? {
+            Token::Number(value) => Ok(factor * value),
+            token => Err(error(
+                line,
+                format!("Expected a number, found {:?}.", token),
+            )),
+        }
+    }
+
+    // "name:" at the front
+    fn label(&mut self) -> Option<&'a str> {
+        if self.is_name(0) && self.is_operator(1, ":") {
+            let name = self.name().unwrap();
+            self.position += 1;
+            Option::Some(name)
+        } else {
+            Option::None
+        }
+    }
+
+    // linear terms and constants until a sense or the end of the section
+    fn expression(&mut self, builder: &mut ModelBuilder) -> Result<LinearPolynomial, ModelError> {
+        let mut ret = LinearPolynomial::new();
+        let mut first = true;
+        while !self.is_empty() && !self.is_sense(0) {
+            let line = self.line();
+            let mut coefficient = 1.;
+            if self.is_operator(0, "+") || self.is_operator(0, "-") {
+                if self.is_operator(0, "-") {
+                    coefficient = -1.;
+                }
+                self.position += 1;
+            } else if !first {
+                return Err(error(
+                    line,
+                    format!("Expected + or -, found {:?}.", self.peek(0).unwrap()),
+                ));
+            }
+            if self.is_operator(0, "[") {
+                return Err(error(
+                    line,
+                    String::from("Quadratic terms cannot be read into a linear model."),
+                ));
+            }
+            let number = if self.is_number(0) {
+                match self.next()? {
+                    Token::Number(value) => Option::Some(*value),
+                    _ => Option::None,
+                }
+            } else {
+                Option::None
+            };
+            if self.is_name(0) {
+                let name = self.name()?;
+                ret.add_monomial(builder.monomial(coefficient * number.unwrap_or(1.), name));
+            } else if let Option::Some(number) = number {
+                ret.add_constant(coefficient * number);
+            } else {
+                return Err(error(
+                    line,
+                    format!("Expected a term, found {:?}.", self.peek(0).unwrap()),
+                ));
+            }
+            first = false;
+        }
+        Ok(ret)
+    }
+}
+
+struct LpReader {
+    builder: ModelBuilder,
+    // unnamed constraints are called c<i> and ic<i> after their position, as in the writer
+    indicator_constraints: usize,
+}
+
+impl LpReader {
+    fn objective(&mut self, tokens: &mut TokenStream) -> Result<(), ModelError> {
+        tokens.label();
+        self.builder.objective = tokens.expression(&mut self.builder)?;
+        if !tokens.is_empty() {
+            return Err(error(
+                tokens.line(),
+                format!("Unexpected {:?} in the objective.", tokens.peek(0).unwrap()),
+            ));
+        }
+        Ok(())
+    }
+
+    // [name:] [indicator = 0|1 ->] expression sense rhs
+    fn constraints(&mut self, tokens: &mut TokenStream) -> Result<(), ModelError> {
+        while !tokens.is_empty() {
+            let name = tokens.label();
+            let line = tokens.line();
+            let indicator = if tokens.is_name(0)
+                && tokens.is_operator(1, "=")
+                && tokens.is_number(2)
+                && tokens.is_operator(3, "->")
+            {
+                let indicator = tokens.name()?;
+                self.builder.column(indicator);
+                tokens.position += 1;
+                let value = tokens.number()?;
+                tokens.position += 1;
+                if value != 0. && value != 1. {
+                    return Err(error(
+                        line,
+                        format!("Indicator value {} is not 0 or 1.", value),
+                    ));
+                }
+                Option::Some((indicator, value == 1.))
+            } else {
+                Option::None
+            };
+            let expression = tokens.expression(&mut self.builder)?;
+            let sign = tokens.sense()?;
+            let rhs = tokens.number()?;
+            let name = match (name, indicator) {
+                (Option::Some(name), _) => name.to_string(),
+                (Option::None, Option::Some(_)) => format!("ic{}", self.indicator_constraints),
+                (Option::None, Option::None) => format!("c{}", self.builder.constraints.len()),
+            };
+            let constant = expression.constant();
+            let constraint =
+                Constraint::new(expression - constant, sign, rhs - constant).with_name(&name);
+            match indicator {
+                Option::Some((indicator, value)) => {
+                    self.indicator_constraints += 1;
+                    self.builder
+                        .add_indicator_constraint(line, indicator, value, constraint)
+                }
+                Option::None => self.builder.constraints.push(constraint),
+            }
+        }
+        Ok(())
+    }
+
+    // x free | [lb <=] x [<= ub] | x = v, the senses being either way
+    fn bounds(&mut self, tokens: &mut TokenStream) -> Result<(), ModelError> {
+        while !tokens.is_empty() {
+            let mut bounds: Vec<(Sign, f64)> = Vec::new();
+            if !tokens.is_name(0) || tokens.is_infinity(0) {
+                let value = tokens.number()?;
+                let sign = tokens.sense()?;
+                bounds.push((sign.reverse(), value));
+            }
+            let line = tokens.line();
+            let name = tokens.name()?;
+            let column = self.builder.column(name);
+            let variable = self.builder.variable(column);
+            if matches!(tokens.peek(0), Option::Some(Token::Name(word)) if word.to_lowercase() == "free")
+            {
+                tokens.position += 1;
+                variable.lower_bound = f64::NEG_INFINITY;
+                variable.upper_bound = f64::INFINITY;
+                continue;
+            }
+            if tokens.is_sense(0) {
+                let sign = tokens.sense()?;
+                let value = tokens.number()?;
+                bounds.push((sign, value));
+            }
+            if bounds.is_empty() {
+                return Err(error(line, format!("Variable {} has no bound.", name)));
+            }
+            for (sign, value) in bounds {
+                match sign {
+                    Sign::LessEqual => variable.upper_bound = value,
+                    Sign::GreaterEqual => variable.lower_bound = value,
+                    _ => {
+                        variable.lower_bound = value;
+                        variable.upper_bound = value;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn categories(&mut self, section: Section, tokens: &mut TokenStream) -> Result<(), ModelError> {
+        while !tokens.is_empty() {
+            let name = tokens.name()?;
+            let column = self.builder.column(name);
+            let variable = self.builder.variable(column);
+            variable.category = match (section, variable.category) {
+                (Section::Binaries, _) => {
+                    // keep bounds within [0, 1], such as a fixed binary written as b = 1
+                    variable.lower_bound = variable.lower_bound.max(0.);
+                    variable.upper_bound = variable.upper_bound.min(1.);
+                    VariableCategory::Binary
+                }
+                (
+                    Section::Generals,
+                    VariableCategory::SemiContinuous | VariableCategory::SemiInteger,
+                ) => VariableCategory::SemiInteger,
+                (Section::Generals, _) => VariableCategory::Integer,
+                (_, VariableCategory::Integer | VariableCategory::SemiInteger) => {
+                    VariableCategory::SemiInteger
+                }
+                (_, _) => VariableCategory::SemiContinuous,
+            };
+        }
+        Ok(())
+    }
+
+    // [name:] S1|S2 :: (x:weight)*
+    fn special_ordered_sets(&mut self, tokens: &mut TokenStream) -> Result<(), ModelError> {
+        while !tokens.is_empty() {
+            let name = if tokens.is_operator(1, ":") {
+                tokens.label().unwrap().to_string()
+            } else {
+                format!("sos{}", self.builder.special_ordered_sets.len())
+            };
+            let line = tokens.line();
+            let category = match tokens.name()? {
+                "S1" | "s1" => SosCategory::Sos1,
+                "S2" | "s2" => SosCategory::Sos2,
+                code => return Err(error(line, format!("Unknown set type {}.", code))),
+            };
+            tokens.operator("::")?;
+            let mut set = SpecialOrderedSet {
+                category: category,
+                symbols: Vec::new(),
+                weights: Vec::new(),
+                name: name,
+            };
+            while tokens.is_name(0) && tokens.is_operator(1, ":") && !tokens.is_name(2) {
+                let member = tokens.name()?;
+                tokens.position += 1;
+                set.symbols.push(self.builder.symbol(member));
+                set.weights.push(tokens.number()?);
+            }
+            self.builder.special_ordered_sets.push(set);
+        }
+        Ok(())
+    }
+
+    fn section(&mut self, section: Section, tokens: &mut TokenStream) -> Result<(), ModelError> {
+        match section {
+            Section::Objective => self.objective(tokens),
+            Section::Constraints => self.constraints(tokens),
+            Section::Bounds => self.bounds(tokens),
+            Section::Generals | Section::Binaries | Section::SemiContinuous => {
+                self.categories(section, tokens)
+            }
+            Section::Sos => self.special_ordered_sets(tokens),
+        }
+    }
+}
+
+// a section starts with its keyword on an unindented line of its own, the objective sense being
+// the only one that may be followed by the objective itself if not indented; "\ Problem name: ..."
+// before the first section names the model
+pub fn read_lp<R: BufRead>(reader: &mut R) -> Result<LinearMetaModel, ModelError> {
+    let mut parser = LpReader {
+        builder: ModelBuilder::new(),
+        indicator_constraints: 0,
+    };
+    let mut sections: Vec<(usize, Section, Vec<(usize, Token)>)> = Vec::new();
+    let mut last_line = 0;
+    for (i, text) in reader.lines().enumerate() {
+        let line = i + 1;
+        last_line = line;
+        let text = text.map_err(|e| error(line, format!("Failed to read the model: {}.", e)))?;
+        let (text, comment) = match text.find('\\') {
+            Option::Some(position) => (&text[..position], &text[position + 1..]),
+            Option::None => (text.as_str(), ""),
+        };
+        if sections.is_empty() {
+            if let Option::Some(name) = comment.trim().strip_prefix("Problem name:") {
+                parser.builder.name = name.trim().to_string();
+            }
+        }
+        let first = text.split_whitespace().next().unwrap_or("");
+        if text.trim().to_lowercase() == "end" {
+            break;
+        }
+        let indented = text.starts_with(char::is_whitespace);
+        if let (false, Option::Some((section, category))) = (indented, section_of(text)) {
+            sections.push((line, section, Vec::new()));
+            if let Option::Some(category) = category {
+                parser.builder.object_category = category;
+            }
+            continue;
+        }
+        if let (false, Option::Some((Section::Objective, Option::Some(category)))) =
+            (indented, section_of(first))
+        {
+            parser.builder.object_category = category;
+            sections.push((line, Section::Objective, Vec::new()));
+            tokenize(
+                line,
+                &text[first.len()..],
+                &mut sections.last_mut().unwrap().2,
+            )?;
+            continue;
+        }
+        match sections.last_mut() {
+            Option::Some((_, _, tokens)) => tokenize(line, text, tokens)?,
+            Option::None if text.trim().is_empty() => {}
+            Option::None => {
+                return Err(error(line, String::from("Expected the objective sense.")));
+            }
+        }
+    }
+    for (i, (_, section, tokens)) in sections.iter().enumerate() {
+        let end = sections.get(i + 1).map_or(last_line, |(line, _, _)| *line);
+        parser.section(*section, &mut TokenStream::new(tokens, end))?;
+    }
+    parser.builder.build()
+}
+
+#[cfg(test)]
+const TEXT: &str = "\\ Problem name: demo
+Maximize
+ obj: 3 x - y + 2
+Subject To
+ cover: x + 2 y >= 1
+ x - y
+   <= 4
+ ind: b = 0 -> x + s <= 2
+Bounds
+ x free
+ -2 <= y <= 5
+ s <= 10
+Generals
+ y
+Binaries
+ b
+Semi-Continuous
+ s
+SOS
+ set: S2:: x:1 y:2
+End
+";
+
+#[test]
+fn test_read_lp() {
+    use super::lp_writer::write_lp;
+    use ospf_rust_base::ErrorCode;
+
+    let model = read_lp(&mut TEXT.as_bytes()).unwrap();
+    assert_eq!(model.name, "demo");
+    assert_eq!(model.object_category(), ObjectCategory::Maximum);
+    assert_eq!(model.objective().constant(), 2.);
+    let columns: Vec<(&str, VariableCategory, f64, f64)> = model
+        .variables()
+        .iter()
+        .map(|variable| {
+            (
+                variable.symbol.name.as_str(),
+                variable.category,
+                variable.lower_bound,
+                variable.upper_bound,
+            )
+        })
+        .collect();
+    assert_eq!(
+        columns,
+        vec![
+            (
+                "x",
+                VariableCategory::Continuous,
+                f64::NEG_INFINITY,
+                f64::INFINITY
+            ),
+            ("y", VariableCategory::Integer, -2., 5.),
+            ("b", VariableCategory::Binary, 0., 1.),
+            ("s", VariableCategory::SemiContinuous, 0., 10.),
+        ]
+    );
+    assert_eq!(model.constraints()[1].name, "c1");
+    assert_eq!(model.constraints()[1].upper_bound(), 4.);
+    assert!(!model.indicator_constraints()[0].active_value);
+    assert_eq!(model.special_ordered_sets()[0].category, SosCategory::Sos2);
+
+    // the writer gives the same model back
+    let mut buffer: Vec<u8> = Vec::new();
+    write_lp(&model, &mut buffer).unwrap();
+    let copy = read_lp(&mut buffer.as_slice()).unwrap();
+    let mut copy_buffer: Vec<u8> = Vec::new();
+    write_lp(&copy, &mut copy_buffer).unwrap();
+    assert_eq!(
+        String::from_utf8(buffer).unwrap(),
+        String::from_utf8(copy_buffer).unwrap()
+    );
+
+    let error = read_lp(&mut TEXT.replace("   <= 4", "   <= y").as_bytes())
+        .err()
+        .unwrap();
+    assert_eq!(error.code(), ErrorCode::DeserializationFailed);
+    assert_eq!(
+        error.what(),
+        "Line 7: Expected a number, found Name(\"y\")."
+    );
+
+    // a fixed binary stays fixed
+    let fixed = read_lp(&mut TEXT.replace(" s <= 10", " s <= 10\n b = 1").as_bytes()).unwrap();
+    assert_eq!(fixed.variables()[2].lower_bound, 1.);
+    assert_eq!(fixed.variables()[2].upper_bound, 1.);
+    let mut buffer: Vec<u8> = Vec::new();
+    write_lp(&fixed, &mut buffer).unwrap();
+    let copy = read_lp(&mut buffer.as_slice()).unwrap();
+    assert_eq!(copy.variables()[2].category, VariableCategory::Binary);
+    assert_eq!(copy.variables()[2].lower_bound, 1.);
+    assert_eq!(copy.variables()[2].upper_bound, 1.);
+
+    // indented keywords are columns
+    let keywords = read_lp(
+        &mut TEXT
+            .replace("Maximize", "Minimize")
+            .replace(" b = 0", " max = 0")
+            .replace("Binaries\n b", "Binaries\n max\n bin")
+            .as_bytes(),
+    )
+    .unwrap();
+    assert_eq!(keywords.object_category(), ObjectCategory::Minimum);
+    assert_eq!(keywords.variables()[2].symbol.name, "max");
+    assert_eq!(keywords.variables()[2].category, VariableCategory::Binary);
+    assert_eq!(keywords.variables()[4].symbol.name, "bin");
+    assert_eq!(keywords.variables()[4].category, VariableCategory::Binary);
+}
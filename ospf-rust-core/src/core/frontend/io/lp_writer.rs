@@ -0,0 +1,307 @@
+use super::super::constraint::SosCategory;
+use super::super::expression::*;
+use super::super::model::{LinearMetaModel, ModelError, ModelVariable, QuadraticMetaModel};
+use super::super::variable::VariableCategory;
+use super::model_view::*;
+use ospf_rust_base::ErrorCode;
+use std::io::Write;
+
+// readers of the LP format limit the length of a line
+const LINE_WIDTH: usize = 255;
+
+fn number(value: f64) -> String {
+    if value == f64::INFINITY {
+        String::from("+inf")
+    } else if value == f64::NEG_INFINITY {
+        String::from("-inf")
+    } else {
+        format!("{}", value)
+    }
+}
+
+// "2 x" as the first term and "- 2 x" or "+ x" afterwards
+fn term(coefficient: f64, name: &str, first: bool) -> String {
+    let body = if coefficient.abs() == 1. && !name.is_empty() {
+        name.to_string()
+    } else if name.is_empty() {
+        number(coefficient.abs())
+    } else {
+        format!("{} {}", number(coefficient.abs()), name)
+    };
+    if coefficient < 0. {
+        format!("- {}", body)
+    } else if first {
+        body
+    } else {
+        format!("+ {}", body)
+    }
+}
+
+struct LpBuffer {
+    text: String,
+    line_start: usize,
+}
+
+impl LpBuffer {
+    fn new() -> Self {
+        Self {
+            text: String::new(),
+            line_start: 0,
+        }
+    }
+
+    fn line(&mut self, line: &str) {
+        self.text.push_str(line);
+        self.text.push('\n');
+        self.line_start = self.text.len();
+    }
+
+    // a token of an expression, wrapped onto a continuation line if needed
+    fn token(&mut self, token: &str) {
+        if self.text.len() - self.line_start + token.len() + 1 > LINE_WIDTH {
+            self.text.push('\n');
+            self.line_start = self.text.len();
+        }
+        self.text.push(' ');
+        self.text.push_str(token);
+    }
+
+    fn end_line(&mut self) {
+        self.line("");
+    }
+
+    // the linear terms and then the quadratic ones in brackets, whose coefficients
+    // are doubled in the objective as it is written as [ ... ] / 2
+    fn expression(
+        &mut self,
+        view: &ModelView,
+        linear: &LinearPolynomial,
+        quadratic: &[QuadraticMonomial],
+        objective: bool,
+    ) -> Result<(), ModelError> {
+        let mut first = true;
+        for monomial in linear.monomials() {
+            self.token(&term(
+                monomial.coefficient,
+                view.name_of(&monomial.symbol)?,
+                first,
+            ));
+            first = false;
+        }
+        if !quadratic.is_empty() {
+            self.token(if first { "[" } else { "+ [" });
+            let factor = if objective { 2. } else { 1. };
+            for (i, monomial) in quadratic.iter().enumerate() {
+                let name = if monomial.is_square() {
+                    format!("{} ^ 2", view.name_of(&monomial.symbol1)?)
+                } else {
+                    format!(
+                        "{} * {}",
+                        view.name_of(&monomial.symbol1)?,
+                        view.name_of(&monomial.symbol2)?
+                    )
+                };
+                self.token(&term(factor * monomial.coefficient, &name, i == 0));
+            }
+            self.token(if objective { "] / 2" } else { "]" });
+            first = false;
+        }
+        if first {
+            // the readers expect at least one term
+            self.token("0");
+        }
+        Ok(())
+    }
+}
+
+fn bound(variable: &ModelVariable, name: &str) -> Option<String> {
+    let (lb, ub) = (variable.lower_bound, variable.upper_bound);
+    if variable.category == VariableCategory::Binary && lb == 0. && ub == 1. {
+        Option::None
+    } else if lb == 0. && ub == f64::INFINITY {
+        Option::None
+    } else if lb == ub {
+        Option::Some(format!(" {} = {}", name, number(lb)))
+    } else if lb == f64::NEG_INFINITY && ub == f64::INFINITY {
+        Option::Some(format!(" {} free", name))
+    } else if ub == f64::INFINITY {
+        Option::Some(format!(" {} >= {}", name, number(lb)))
+    } else {
+        Option::Some(format!(" {} <= {} <= {}", number(lb), name, number(ub)))
+    }
+}
+
+fn lp_text(view: &ModelView) -> Result<String, ModelError> {
+    let model = view.model;
+    let mut buffer = LpBuffer::new();
+    buffer.line(&format!("\\ Problem name: {}", model.name));
+    buffer.line(if view.is_maximum() {
+        "Maximize"
+    } else {
+        "Minimize"
+    });
+    buffer.text.push_str(&format!(" {}:", OBJECTIVE_ROW));
+    buffer.expression(
+        view,
+        view.objective.linear(),
+        view.objective.monomials(),
+        true,
+    )?;
+    if view.objective.constant() != 0. {
+        buffer.token(&term(view.objective.constant(), "", false));
+    }
+    buffer.end_line();
+
+    buffer.line("Subject To");
+    for row in view.rows(false)? {
+        buffer.text.push_str(&format!(" {}:", row.name));
+        if let Option::Some((indicator, value)) = row.indicator {
+            buffer.token(&format!(
+                "{} = {} ->",
+                view.name_of(indicator)?,
+                value as u8
+            ));
+        }
+        buffer.expression(view, &row.linear, &row.quadratic, false)?;
+        buffer.token(match row.sense {
+            RowSense::LessEqual => "<=",
+            RowSense::GreaterEqual => ">=",
+            RowSense::Equal => "=",
+        });
+        buffer.token(&number(row.rhs));
+        buffer.end_line();
+    }
+
+    buffer.line("Bounds");
+    for (i, variable) in model.variables().iter().enumerate() {
+        if let Option::Some(line) = bound(variable, model.name_of(i)) {
+            buffer.line(&line);
+        }
+    }
+    let sections: [(&str, &[VariableCategory]); 3] = [
+        (
+            "Generals",
+            &[VariableCategory::Integer, VariableCategory::SemiInteger],
+        ),
+        ("Binaries", &[VariableCategory::Binary]),
+        (
+            "Semi-Continuous",
+            &[
+                VariableCategory::SemiContinuous,
+                VariableCategory::SemiInteger,
+            ],
+        ),
+    ];
+    for (section, categories) in sections {
+        let columns: Vec<usize> = (0..model.variables().len())
+            .filter(|i| categories.contains(&model.variables()[*i].category))
+            .collect();
+        if columns.is_empty() {
+            continue;
+        }
+        buffer.line(section);
+        for column in columns {
+            buffer.token(model.name_of(column));
+        }
+        buffer.end_line();
+    }
+
+    if !model.special_ordered_sets().is_empty() {
+        buffer.line("SOS");
+        for (i, set) in model.special_ordered_sets().iter().enumerate() {
+            let name = if set.name.is_empty() {
+                format!("sos{}", i)
+            } else {
+                set.name.clone()
+            };
+            buffer.text.push_str(&format!(" {}:", name));
+            buffer.token(match set.category {
+                SosCategory::Sos1 => "S1::",
+                SosCategory::Sos2 => "S2::",
+            });
+            for (symbol, weight) in set.symbols.iter().zip(set.weights.iter()) {
+                buffer.token(&format!("{}:{}", view.name_of(symbol)?, number(*weight)));
+            }
+            buffer.end_line();
+        }
+    }
+    buffer.line("End");
+    Ok(buffer.text)
+}
+
+fn write_text<W: Write>(text: &str, writer: &mut W) -> Result<(), ModelError> {
+    writer.write_all(text.as_bytes()).map_err(|error| {
+        ModelError::new(
+            ErrorCode::SerializationFailed,
+            format!("Failed to write the model: {}.", error),
+        )
+    })
+}
+
+// ranged constraints are written as two rows <name>_lb and <name>_ub
+pub fn write_lp<W: Write>(model: &LinearMetaModel, writer: &mut W) -> Result<(), ModelError> {
+    write_text(&lp_text(&ModelView::from(model))?, writer)
+}
+
+pub fn write_quadratic_lp<W: Write>(
+    model: &QuadraticMetaModel,
+    writer: &mut W,
+) -> Result<(), ModelError> {
+    write_text(&lp_text(&ModelView::from(model))?, writer)
+}
+
+#[test]
+fn test_write_lp() {
+    use super::super::constraint::*;
+    use super::super::variable::*;
+
+    let x = RealVar::new_with_name("x");
+    let mut y = IntVar::new_with_name("y");
+    y.range.set_lb(-2).unwrap();
+    y.range.set_ub(5).unwrap();
+    let b = BinVar::new_with_name("b");
+    let mut model = QuadraticMetaModel::new_with_name("demo");
+    model.add_vars([&x]).unwrap();
+    model.add_var(&y).unwrap();
+    model.add_var(&b).unwrap();
+    model
+        .add_constraint((&x + 2. * &y).geq(1.).with_name("cover"))
+        .unwrap();
+    model
+        .add_constraint(Constraint::new_ranged(-1., &x - &y, 4.).unwrap())
+        .unwrap();
+    model.add_quadratic_constraint((&x * &y).leq(3.)).unwrap();
+    model
+        .add_indicator_constraint(IndicatorConstraint::new(&b, (&x).leq(2.)))
+        .unwrap();
+    model
+        .add_special_ordered_set(SpecialOrderedSet::new_sos1([&x]))
+        .unwrap();
+    model.maximize(3. * &x - &y + &x * &x + 5.).unwrap();
+
+    let mut buffer: Vec<u8> = Vec::new();
+    write_quadratic_lp(&model, &mut buffer).unwrap();
+    assert_eq!(
+        String::from_utf8(buffer).unwrap(),
+        "\\ Problem name: demo
+Maximize
+ obj: 3 x - y + [ 2 x ^ 2 ] / 2 + 5
+Subject To
+ cover: x + 2 y >= 1
+ c1_lb: x - y >= -1
+ c1_ub: x - y <= 4
+ qc0: [ x * y ] <= 3
+ ic0: b = 1 -> x <= 2
+Bounds
+ x free
+ -2 <= y <= 5
+Generals
+ y
+Binaries
+ b
+SOS
+ sos0: S1:: x:1
+End
+"
+    );
+}
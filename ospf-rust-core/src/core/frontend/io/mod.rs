@@ -0,0 +1,11 @@
+pub mod lp_reader;
+pub mod lp_writer;
+mod model_builder;
+mod model_view;
+pub mod mps_reader;
+pub mod mps_writer;
+
+pub use lp_reader::read_lp;
+pub use lp_writer::{write_lp, write_quadratic_lp};
+pub use mps_reader::read_mps;
+pub use mps_writer::{write_mps, write_quadratic_mps, MpsFormat};
@@ -0,0 +1,138 @@
+use super::super::constraint::{Constraint, IndicatorConstraint, SpecialOrderedSet};
+use super::super::expression::*;
+use super::super::model::{
+    LinearMetaModel, ModelError, ModelVariable, ObjectCategory, VariableEncoding,
+};
+use super::super::variable::item::new_identifier;
+use super::super::variable::VariableCategory;
+use ospf_rust_base::ErrorCode;
+use std::collections::HashMap;
+
+pub(crate) fn error(line: usize, message: String) -> ModelError {
+    ModelError::new(
+        ErrorCode::DeserializationFailed,
+        format!("Line {}: {}", line, message),
+    )
+}
+
+// what the readers collect before the model is built, as the category and the bounds
+// of a column are only known at the end of a file; the columns are indices of a
+// fresh identifier, named as in the file
+pub(crate) struct ModelBuilder {
+    pub name: String,
+    _identifier: u64,
+    _columns: Vec<ModelVariable>,
+    _names: HashMap<String, usize>,
+    pub objective: LinearPolynomial,
+    pub object_category: ObjectCategory,
+    pub constraints: Vec<Constraint>,
+    // with the line of the indicator, whose category is checked when building
+    _indicator_constraints: Vec<(usize, IndicatorConstraint)>,
+    pub special_ordered_sets: Vec<SpecialOrderedSet>,
+}
+
+impl ModelBuilder {
+    pub fn new() -> Self {
+        Self {
+            name: String::new(),
+            _identifier: new_identifier(),
+            _columns: Vec::new(),
+            _names: HashMap::new(),
+            objective: LinearPolynomial::new(),
+            object_category: ObjectCategory::Minimum,
+            constraints: Vec::new(),
+            _indicator_constraints: Vec::new(),
+            special_ordered_sets: Vec::new(),
+        }
+    }
+
+    pub fn find(&self, name: &str) -> Option<usize> {
+        self._names.get(name).copied()
+    }
+
+    // the column of the name, a continuous one in [0, inf) when it is new
+    pub fn column(&mut self, name: &str) -> usize {
+        if let Option::Some(column) = self._names.get(name) {
+            return *column;
+        }
+        let column = self._columns.len();
+        self._columns.push(ModelVariable {
+            symbol: VariableSymbol::new(self._identifier, column, name),
+            category: VariableCategory::Continuous,
+            lower_bound: 0.,
+            upper_bound: f64::INFINITY,
+            encoding: VariableEncoding::Native,
+        });
+        self._names.insert(name.to_string(), column);
+        column
+    }
+
+    pub fn variable(&mut self, column: usize) -> &mut ModelVariable {
+        &mut self._columns[column]
+    }
+
+    pub fn symbol(&mut self, name: &str) -> VariableSymbol {
+        let column = self.column(name);
+        self._columns[column].symbol.clone()
+    }
+
+    pub fn monomial(&mut self, coefficient: f64, name: &str) -> LinearMonomial {
+        LinearMonomial::new(coefficient, self.symbol(name))
+    }
+
+    pub fn add_indicator_constraint(
+        &mut self,
+        line: usize,
+        indicator: &str,
+        active_value: bool,
+        constraint: Constraint,
+    ) {
+        let symbol = self.symbol(indicator);
+        self._indicator_constraints.push((
+            line,
+            IndicatorConstraint {
+                indicator: symbol,
+                active_value: active_value,
+                name: constraint.name.clone(),
+                constraint: constraint,
+            },
+        ));
+    }
+
+    pub fn build(self) -> Result<LinearMetaModel, ModelError> {
+        let mut model = LinearMetaModel::new_with_name(&self.name);
+        for (line, constraint) in &self._indicator_constraints {
+            let variable = &self._columns[self._names[&constraint.indicator.name]];
+            let binary = match variable.category {
+                VariableCategory::Binary => true,
+                VariableCategory::Integer => {
+                    variable.lower_bound >= 0. && variable.upper_bound <= 1.
+                }
+                _ => false,
+            };
+            if !binary {
+                return Err(error(
+                    *line,
+                    format!("Indicator {} is not a binary variable.", variable.symbol),
+                ));
+            }
+        }
+        for variable in self._columns {
+            model.add_model_variable(variable)?;
+        }
+        match self.object_category {
+            ObjectCategory::Minimum => model.minimize(self.objective),
+            ObjectCategory::Maximum => model.maximize(self.objective),
+        }?;
+        for constraint in self.constraints {
+            model.add_constraint(constraint)?;
+        }
+        for (_, constraint) in self._indicator_constraints {
+            model.add_indicator_constraint(constraint)?;
+        }
+        for set in self.special_ordered_sets {
+            model.add_special_ordered_set(set)?;
+        }
+        Ok(model)
+    }
+}
@@ -0,0 +1,184 @@
+use super::super::constraint::QuadraticConstraint;
+use super::super::expression::*;
+use super::super::model::{LinearMetaModel, ModelError, ObjectCategory, QuadraticMetaModel};
+use ospf_rust_base::ErrorCode;
+use std::collections::HashSet;
+
+// name of the objective row, which no constraint row may take
+pub(crate) const OBJECTIVE_ROW: &str = "obj";
+
+// what the writers need from a linear or a quadratic model
+pub(crate) struct ModelView<'a> {
+    pub model: &'a LinearMetaModel,
+    pub objective: QuadraticPolynomial,
+    pub quadratic_constraints: &'a [QuadraticConstraint],
+}
+
+impl<'a> From<&'a LinearMetaModel> for ModelView<'a> {
+    fn from(model: &'a LinearMetaModel) -> Self {
+        Self {
+            model: model,
+            objective: QuadraticPolynomial::from(model.objective().clone()),
+            quadratic_constraints: &[],
+        }
+    }
+}
+
+impl<'a> From<&'a QuadraticMetaModel> for ModelView<'a> {
+    fn from(model: &'a QuadraticMetaModel) -> Self {
+        Self {
+            model: model.linear(),
+            objective: model.objective().clone(),
+            quadratic_constraints: model.quadratic_constraints(),
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) enum RowSense {
+    LessEqual,
+    GreaterEqual,
+    Equal,
+}
+
+pub(crate) struct Row<'a> {
+    pub name: String,
+    pub linear: LinearPolynomial,
+    pub quadratic: Vec<QuadraticMonomial>,
+    pub sense: RowSense,
+    pub rhs: f64,
+    // a GreaterEqual row with a range covers [rhs, rhs + range]
+    pub range: Option<f64>,
+    pub indicator: Option<(&'a VariableSymbol, bool)>,
+}
+
+impl<'a> ModelView<'a> {
+    pub fn is_maximum(&self) -> bool {
+        self.model.object_category() == ObjectCategory::Maximum
+    }
+
+    pub fn column_of(&self, symbol: &VariableSymbol) -> Result<usize, ModelError> {
+        match self.model.column_of(symbol) {
+            Option::Some(column) => Ok(column),
+            Option::None => Err(ModelError::new(
+                ErrorCode::SerializationFailed,
+                format!("Variable {} is not in the model.", symbol),
+            )),
+        }
+    }
+
+    pub fn name_of(&self, symbol: &VariableSymbol) -> Result<&str, ModelError> {
+        Ok(self.model.name_of(self.column_of(symbol)?))
+    }
+
+    // the constraints as rows of a single sense, unnamed ones being called c<i>, qc<i> and
+    // ic<i>; a ranged constraint is split into <name>_lb and <name>_ub unless it is
+    // linear and ranges are supported; row names should be unique, as readers either
+    // reject or merge the repeated ones
+    pub fn rows(&self, ranges: bool) -> Result<Vec<Row<'a>>, ModelError> {
+        let mut rows = Vec::new();
+        for (i, constraint) in self.model.constraints().iter().enumerate() {
+            push_rows(
+                &mut rows,
+                row_name(&constraint.name, "c", i),
+                constraint.expression(),
+                Vec::new(),
+                Option::None,
+                (constraint.lower_bound(), constraint.upper_bound()),
+                ranges,
+            );
+        }
+        for (i, constraint) in self.quadratic_constraints.iter().enumerate() {
+            let expression = constraint.expression();
+            push_rows(
+                &mut rows,
+                row_name(&constraint.name, "qc", i),
+                expression.linear().clone(),
+                expression.monomials().to_vec(),
+                Option::None,
+                (constraint.lower_bound(), constraint.upper_bound()),
+                false,
+            );
+        }
+        for (i, constraint) in self.model.indicator_constraints().iter().enumerate() {
+            push_rows(
+                &mut rows,
+                row_name(&constraint.name, "ic", i),
+                constraint.constraint.expression(),
+                Vec::new(),
+                Option::Some((&constraint.indicator, constraint.active_value)),
+                (
+                    constraint.constraint.lower_bound(),
+                    constraint.constraint.upper_bound(),
+                ),
+                false,
+            );
+        }
+
+        let mut names: HashSet<&str> = HashSet::new();
+        names.insert(OBJECTIVE_ROW);
+        for row in &rows {
+            if !names.insert(&row.name) {
+                return Err(ModelError::new(
+                    ErrorCode::SerializationFailed,
+                    format!("Row {} is repeated.", row.name),
+                ));
+            }
+        }
+        Ok(rows)
+    }
+}
+
+fn row_name(name: &str, prefix: &str, index: usize) -> String {
+    if name.is_empty() {
+        format!("{}{}", prefix, index)
+    } else {
+        name.to_string()
+    }
+}
+
+fn push_rows<'a>(
+    rows: &mut Vec<Row<'a>>,
+    name: String,
+    linear: LinearPolynomial,
+    quadratic: Vec<QuadraticMonomial>,
+    indicator: Option<(&'a VariableSymbol, bool)>,
+    (lb, ub): (f64, f64),
+    ranges: bool,
+) {
+    let mut row = |name: String, sense: RowSense, rhs: f64, range: Option<f64>| {
+        rows.push(Row {
+            name: name,
+            linear: linear.clone(),
+            quadratic: quadratic.clone(),
+            sense: sense,
+            rhs: rhs,
+            range: range,
+            indicator: indicator,
+        })
+    };
+    if lb == ub {
+        row(name, RowSense::Equal, ub, Option::None);
+    } else if lb.is_infinite() && ub.is_infinite() {
+        // a free row restricts nothing
+    } else if lb.is_infinite() {
+        row(name, RowSense::LessEqual, ub, Option::None);
+    } else if ub.is_infinite() {
+        row(name, RowSense::GreaterEqual, lb, Option::None);
+    } else if ranges {
+        row(name, RowSense::GreaterEqual, lb, Option::Some(ub - lb));
+    } else {
+        row(
+            format!("{}_lb", name),
+            RowSense::GreaterEqual,
+            lb,
+            Option::None,
+        );
+        row(
+            format!("{}_ub", name),
+            RowSense::LessEqual,
+            ub,
+            Option::None,
+        );
+    }
+}
@@ -0,0 +1,551 @@
+use super::super::constraint::{Constraint, Sign, SosCategory, SpecialOrderedSet};
+use super::super::expression::*;
+use super::super::model::{LinearMetaModel, ModelError, ModelVariable, ObjectCategory};
+use super::super::variable::VariableCategory;
+use super::model_builder::*;
+use super::mps_writer::MpsFormat;
+use std::collections::HashMap;
+use std::io::BufRead;
+
+// ranges of the 6 fields of a fixed MPS record
+const FIXED_FIELDS: [(usize, usize); 6] = [(1, 3), (4, 12), (14, 22), (24, 36), (39, 47), (49, 61)];
+
+// the usual convention for infinite bounds and right-hand sides
+const INFINITY: f64 = 1e30;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Section {
+    Head,
+    ObjectSense,
+    Rows,
+    Columns,
+    Rhs,
+    Ranges,
+    Bounds,
+    Sos,
+    Indicators,
+}
+
+struct MpsRow {
+    name: String,
+    // N, E, L or G
+    sense: char,
+    expression: LinearPolynomial,
+    rhs: f64,
+    range: Option<f64>,
+    indicator: Option<(usize, String, bool)>,
+}
+
+// the code in the first field, followed by the names and the numbers
+fn fields(line: &str, format: MpsFormat, section: Section) -> Vec<String> {
+    match format {
+        MpsFormat::Fixed => {
+            let chars: Vec<char> = line.chars().collect();
+            let mut ret: Vec<String> = FIXED_FIELDS
+                .iter()
+                .map(|(begin, end)| {
+                    let end = (*end).min(chars.len());
+                    if *begin < end {
+                        chars[*begin..end]
+                            .iter()
+                            .collect::<String>()
+                            .trim()
+                            .to_string()
+                    } else {
+                        String::new()
+                    }
+                })
+                .collect();
+            while ret.last().is_some_and(|field| field.is_empty()) {
+                ret.pop();
+            }
+            ret
+        }
+        MpsFormat::Free => {
+            let tokens: Vec<&str> = line.split_whitespace().collect();
+            let coded = match section {
+                Section::Rows | Section::Bounds | Section::Indicators => true,
+                Section::Sos => tokens.len() >= 3 && tokens[1] == "SOS",
+                _ => false,
+            };
+            let mut ret = Vec::new();
+            if !coded {
+                ret.push(String::new());
+            }
+            ret.extend(tokens.iter().map(|token| token.to_string()));
+            ret
+        }
+    }
+}
+
+fn field(fields: &[String], index: usize, line: usize) -> Result<&str, ModelError> {
+    match fields.get(index) {
+        Option::Some(field) if !field.is_empty() => Ok(field),
+        _ => Err(error(line, format!("Field {} is missing.", index + 1))),
+    }
+}
+
+fn number(text: &str, line: usize) -> Result<f64, ModelError> {
+    match text.parse::<f64>() {
+        Ok(value) if value >= INFINITY => Ok(f64::INFINITY),
+        Ok(value) if value <= -INFINITY => Ok(f64::NEG_INFINITY),
+        Ok(value) if !value.is_nan() => Ok(value),
+        _ => Err(error(line, format!("{} is not a number.", text))),
+    }
+}
+
+fn set_integer(variable: &mut ModelVariable) {
+    variable.category = match variable.category {
+        VariableCategory::SemiContinuous | VariableCategory::SemiInteger => {
+            VariableCategory::SemiInteger
+        }
+        _ => VariableCategory::Integer,
+    };
+}
+
+struct MpsReader {
+    format: MpsFormat,
+    section: Section,
+    builder: ModelBuilder,
+    objective_row: Option<String>,
+    rows: Vec<MpsRow>,
+    row_indices: HashMap<String, usize>,
+    in_marker: bool,
+}
+
+impl MpsReader {
+    fn new(format: MpsFormat) -> Self {
+        Self {
+            format: format,
+            section: Section::Head,
+            builder: ModelBuilder::new(),
+            objective_row: Option::None,
+            rows: Vec::new(),
+            row_indices: HashMap::new(),
+            in_marker: false,
+        }
+    }
+
+    fn header(&mut self, line: usize, text: &str) -> Result<bool, ModelError> {
+        let tokens: Vec<&str> = text.split_whitespace().collect();
+        self.section = match tokens[0] {
+            "NAME" => {
+                self.builder.name = text[4..].trim().to_string();
+                Section::Head
+            }
+            "OBJSENSE" => {
+                if tokens.len() > 1 {
+                    self.object_sense(line, tokens[1])?;
+                    Section::Head
+                } else {
+                    Section::ObjectSense
+                }
+            }
+            "ROWS" => Section::Rows,
+            "COLUMNS" => Section::Columns,
+            "RHS" => Section::Rhs,
+            "RANGES" => Section::Ranges,
+            "BOUNDS" => Section::Bounds,
+            "SOS" => Section::Sos,
+            "INDICATORS" => Section::Indicators,
+            "ENDATA" => return Ok(false),
+            "QUADOBJ" | "QMATRIX" | "QSECTION" | "QCMATRIX" => {
+                return Err(error(
+                    line,
+                    format!("Section {} cannot be read into a linear model.", tokens[0]),
+                ));
+            }
+            _ => return Err(error(line, format!("Unknown section {}.", tokens[0]))),
+        };
+        Ok(true)
+    }
+
+    fn object_sense(&mut self, line: usize, sense: &str) -> Result<(), ModelError> {
+        self.builder.object_category = match sense {
+            "MIN" | "MINIMIZE" => ObjectCategory::Minimum,
+            "MAX" | "MAXIMIZE" => ObjectCategory::Maximum,
+            _ => return Err(error(line, format!("Unknown object sense {}.", sense))),
+        };
+        Ok(())
+    }
+
+    fn row(&self, line: usize, name: &str) -> Result<usize, ModelError> {
+        match self.row_indices.get(name) {
+            Option::Some(row) => Ok(*row),
+            Option::None => Err(error(line, format!("Unknown row {}.", name))),
+        }
+    }
+
+    fn column(&self, line: usize, name: &str) -> Result<usize, ModelError> {
+        match self.builder.find(name) {
+            Option::Some(column) => Ok(column),
+            Option::None => Err(error(line, format!("Unknown column {}.", name))),
+        }
+    }
+
+    fn record(&mut self, line: usize, text: &str) -> Result<(), ModelError> {
+        if self.section == Section::ObjectSense {
+            self.object_sense(line, text.trim())?;
+            self.section = Section::Head;
+            return Ok(());
+        }
+        let fields = fields(text, self.format, self.section);
+        match self.section {
+            Section::Head | Section::ObjectSense => {
+                Err(error(line, String::from("Record out of a section.")))
+            }
+            Section::Rows => self.rows_record(line, &fields),
+            Section::Columns => self.columns_record(line, &fields),
+            Section::Rhs | Section::Ranges => self.rhs_record(line, &fields),
+            Section::Bounds => self.bounds_record(line, &fields),
+            Section::Sos => self.sos_record(line, &fields),
+            Section::Indicators => self.indicators_record(line, &fields),
+        }
+    }
+
+    fn rows_record(&mut self, line: usize, fields: &[String]) -> Result<(), ModelError> {
+        let sense = match field(fields, 0, line)? {
+            "N" => 'N',
+            "E" => 'E',
+            "L" => 'L',
+            "G" => 'G',
+            code => return Err(error(line, format!("Unknown row type {}.", code))),
+        };
+        let name = field(fields, 1, line)?;
+        if self.row_indices.contains_key(name) {
+            return Err(error(line, format!("Row {} is repeated.", name)));
+        }
+        // the first free row is the objective, the others are left out
+        if sense == 'N' && self.objective_row.is_none() {
+            self.objective_row = Option::Some(name.to_string());
+        }
+        self.row_indices.insert(name.to_string(), self.rows.len());
+        self.rows.push(MpsRow {
+            name: name.to_string(),
+            sense: sense,
+            expression: LinearPolynomial::new(),
+            rhs: 0.,
+            range: Option::None,
+            indicator: Option::None,
+        });
+        Ok(())
+    }
+
+    fn columns_record(&mut self, line: usize, fields: &[String]) -> Result<(), ModelError> {
+        if fields.len() > 2 && fields[2] == "'MARKER'" {
+            self.in_marker = match fields.last().unwrap().as_str() {
+                "'INTORG'" => true,
+                "'INTEND'" => false,
+                marker => return Err(error(line, format!("Unknown marker {}.", marker))),
+            };
+            return Ok(());
+        }
+        let name = field(fields, 1, line)?;
+        let column = self.builder.column(name);
+        if self.in_marker {
+            // bounded in [0, inf) by default, as other integer columns
+            set_integer(self.builder.variable(column));
+        }
+        for index in [2, 4] {
+            if index >= fields.len() {
+                break;
+            }
+            let row = self.row(line, field(fields, index, line)?)?;
+            let value = number(field(fields, index + 1, line)?, line)?;
+            let monomial = self.builder.monomial(value, name);
+            if Option::Some(&self.rows[row].name) == self.objective_row.as_ref() {
+                self.builder.objective.add_monomial(monomial);
+            } else if self.rows[row].sense != 'N' {
+                self.rows[row].expression.add_monomial(monomial);
+            }
+        }
+        Ok(())
+    }
+
+    fn rhs_record(&mut self, line: usize, fields: &[String]) -> Result<(), ModelError> {
+        for index in [2, 4] {
+            if index >= fields.len() {
+                break;
+            }
+            let row = self.row(line, field(fields, index, line)?)?;
+            let value = number(field(fields, index + 1, line)?, line)?;
+            let objective = Option::Some(&self.rows[row].name) == self.objective_row.as_ref();
+            match (self.section, objective) {
+                // the right-hand side of the objective is the negative of its constant
+                (Section::Rhs, true) => self.builder.objective.add_constant(-value),
+                (Section::Rhs, false) => self.rows[row].rhs = value,
+                (_, _) if self.rows[row].sense == 'N' => {
+                    return Err(error(
+                        line,
+                        format!("Free row {} has no range.", fields[index]),
+                    ));
+                }
+                (_, _) => self.rows[row].range = Option::Some(value),
+            }
+        }
+        Ok(())
+    }
+
+    fn bounds_record(&mut self, line: usize, fields: &[String]) -> Result<(), ModelError> {
+        let code = field(fields, 0, line)?;
+        let column = self.column(line, field(fields, 2, line)?)?;
+        let value = match fields.get(3) {
+            Option::Some(value) if !value.is_empty() => Option::Some(number(value, line)?),
+            _ => Option::None,
+        };
+        let required = || match value {
+            Option::Some(value) => Ok(value),
+            Option::None => Err(error(line, format!("Bound {} needs a value.", code))),
+        };
+        let variable = self.builder.variable(column);
+        match code {
+            "UP" => variable.upper_bound = required()?,
+            "LO" => variable.lower_bound = required()?,
+            "FX" => {
+                variable.lower_bound = required()?;
+                variable.upper_bound = required()?;
+            }
+            "FR" => {
+                variable.lower_bound = f64::NEG_INFINITY;
+                variable.upper_bound = f64::INFINITY;
+            }
+            "MI" => variable.lower_bound = f64::NEG_INFINITY,
+            "PL" => variable.upper_bound = f64::INFINITY,
+            "BV" => {
+                variable.category = VariableCategory::Binary;
+                variable.lower_bound = 0.;
+                variable.upper_bound = 1.;
+            }
+            "LI" => {
+                set_integer(variable);
+                variable.lower_bound = required()?;
+            }
+            "UI" => {
+                set_integer(variable);
+                variable.upper_bound = required()?;
+            }
+            "SC" => {
+                variable.category = match variable.category {
+                    VariableCategory::Integer | VariableCategory::SemiInteger => {
+                        VariableCategory::SemiInteger
+                    }
+                    _ => VariableCategory::SemiContinuous,
+                };
+                variable.upper_bound = value.unwrap_or(f64::INFINITY);
+            }
+            _ => return Err(error(line, format!("Unknown bound type {}.", code))),
+        }
+        Ok(())
+    }
+
+    fn sos_record(&mut self, line: usize, fields: &[String]) -> Result<(), ModelError> {
+        if !fields[0].is_empty() {
+            let category = match fields[0].as_str() {
+                "S1" => SosCategory::Sos1,
+                "S2" => SosCategory::Sos2,
+                code => return Err(error(line, format!("Unknown set type {}.", code))),
+            };
+            self.builder.special_ordered_sets.push(SpecialOrderedSet {
+                category: category,
+                symbols: Vec::new(),
+                weights: Vec::new(),
+                name: field(fields, 2, line)?.to_string(),
+            });
+            return Ok(());
+        }
+        let name = field(fields, 1, line)?;
+        let column = self.column(line, field(fields, 2, line)?)?;
+        let weight = number(field(fields, 3, line)?, line)?;
+        let symbol = self.builder.variable(column).symbol.clone();
+        match self.builder.special_ordered_sets.last_mut() {
+            Option::Some(set) if set.name == name => {
+                set.symbols.push(symbol);
+                set.weights.push(weight);
+                Ok(())
+            }
+            _ => Err(error(line, format!("Set {} is not declared.", name))),
+        }
+    }
+
+    fn indicators_record(&mut self, line: usize, fields: &[String]) -> Result<(), ModelError> {
+        if field(fields, 0, line)? != "IF" {
+            return Err(error(
+                line,
+                format!("Unknown indicator type {}.", fields[0]),
+            ));
+        }
+        let row = self.row(line, field(fields, 1, line)?)?;
+        if self.rows[row].sense == 'N' {
+            return Err(error(
+                line,
+                format!("Free row {} has no indicator.", fields[1]),
+            ));
+        }
+        let column = field(fields, 2, line)?;
+        self.column(line, column)?;
+        let value = match field(fields, 3, line)? {
+            "1" => true,
+            "0" => false,
+            value => {
+                return Err(error(
+                    line,
+                    format!("Indicator value {} is not 0 or 1.", value),
+                ))
+            }
+        };
+        self.rows[row].indicator = Option::Some((line, column.to_string(), value));
+        Ok(())
+    }
+
+    // ranges extend the rows from their right-hand sides, with the sign of an
+    // equality range telling the direction
+    fn build(mut self) -> Result<LinearMetaModel, ModelError> {
+        for row in self.rows {
+            let rhs = row.rhs;
+            let constraint = match (row.sense, row.range) {
+                ('N', _) => continue,
+                ('E', Option::None) => Constraint::new(row.expression, Sign::Equal, rhs),
+                ('L', Option::None) => Constraint::new(row.expression, Sign::LessEqual, rhs),
+                (_, Option::None) => Constraint::new(row.expression, Sign::GreaterEqual, rhs),
+                ('E', Option::Some(range)) if range < 0. => {
+                    Constraint::new_ranged(rhs + range, row.expression, rhs)?
+                }
+                ('E', Option::Some(range)) | ('G', Option::Some(range)) => {
+                    Constraint::new_ranged(rhs, row.expression, rhs + range.abs())?
+                }
+                (_, Option::Some(range)) => {
+                    Constraint::new_ranged(rhs - range.abs(), row.expression, rhs)?
+                }
+            }
+            .with_name(&row.name);
+            match row.indicator {
+                Option::Some((line, indicator, value)) => self
+                    .builder
+                    .add_indicator_constraint(line, &indicator, value, constraint),
+                Option::None => self.builder.constraints.push(constraint),
+            }
+        }
+        self.builder.build()
+    }
+}
+
+// quadratic sections are refused, as they do not fit a linear model
+pub fn read_mps<R: BufRead>(
+    reader: &mut R,
+    format: MpsFormat,
+) -> Result<LinearMetaModel, ModelError> {
+    let mut parser = MpsReader::new(format);
+    for (i, text) in reader.lines().enumerate() {
+        let line = i + 1;
+        let text = text.map_err(|e| error(line, format!("Failed to read the model: {}.", e)))?;
+        let text = text.trim_end();
+        if text.trim().is_empty() || text.starts_with('*') {
+            continue;
+        }
+        if text.starts_with(char::is_whitespace) {
+            parser.record(line, text)?;
+        } else if !parser.header(line, text)? {
+            break;
+        }
+    }
+    parser.build()
+}
+
+#[cfg(test)]
+const TEXT: &str = "NAME demo
+OBJSENSE
+    MAX
+ROWS
+ N obj
+ L cap
+ G gap
+ E link
+COLUMNS
+   MARKER 'MARKER' 'INTORG'
+   x obj 1 cap 1
+   x gap 1
+   MARKER 'MARKER' 'INTEND'
+   y obj -1 cap 1
+   y gap -2
+   b link 1
+   s link -1
+RHS
+   RHS obj -1 cap 4
+   RHS gap 1
+RANGES
+   RNG gap 2
+BOUNDS
+ PL BND x
+ FR BND y
+ BV BND b
+ LO BND s 1
+ SC BND s 5
+SOS
+ S1 SOS set
+   set x 1
+   set y 2
+INDICATORS
+ IF cap b 1
+ENDATA
+";
+
+#[test]
+fn test_read_mps() {
+    use super::mps_writer::write_mps;
+    use ospf_rust_base::ErrorCode;
+
+    let model = read_mps(&mut TEXT.as_bytes(), MpsFormat::Free).unwrap();
+    assert_eq!(model.name, "demo");
+    assert_eq!(model.object_category(), ObjectCategory::Maximum);
+    assert_eq!(model.variables().len(), 4);
+    let categories: Vec<VariableCategory> = model
+        .variables()
+        .iter()
+        .map(|variable| variable.category)
+        .collect();
+    assert_eq!(
+        categories,
+        vec![
+            VariableCategory::Integer,
+            VariableCategory::Continuous,
+            VariableCategory::Binary,
+            VariableCategory::SemiContinuous
+        ]
+    );
+    assert_eq!(model.objective().constant(), 1.);
+    assert_eq!(model.constraints().len(), 2);
+    assert_eq!(
+        (
+            model.constraints()[0].lower_bound(),
+            model.constraints()[0].upper_bound()
+        ),
+        (1., 3.)
+    );
+    assert_eq!(
+        model.indicator_constraints()[0].constraint.upper_bound(),
+        4.
+    );
+    assert_eq!(model.special_ordered_sets()[0].weights, vec![1., 2.]);
+
+    // the writer gives the same model back, in both formats
+    for format in [MpsFormat::Free, MpsFormat::Fixed] {
+        let mut buffer: Vec<u8> = Vec::new();
+        write_mps(&model, format, &mut buffer).unwrap();
+        let copy = read_mps(&mut buffer.as_slice(), format).unwrap();
+        let mut copy_buffer: Vec<u8> = Vec::new();
+        write_mps(&copy, format, &mut copy_buffer).unwrap();
+        assert_eq!(
+            String::from_utf8(buffer).unwrap(),
+            String::from_utf8(copy_buffer).unwrap()
+        );
+    }
+
+    let error = read_mps(
+        &mut TEXT.replace("   y gap -2", "   y cut -2").as_bytes(),
+        MpsFormat::Free,
+    )
+    .err()
+    .unwrap();
+    assert_eq!(error.code(), ErrorCode::DeserializationFailed);
+    assert_eq!(error.what(), "Line 15: Unknown row cut.");
+}
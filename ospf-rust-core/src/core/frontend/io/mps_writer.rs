@@ -0,0 +1,457 @@
+use super::super::constraint::SosCategory;
+use super::super::model::{LinearMetaModel, ModelError, ModelVariable, QuadraticMetaModel};
+use super::super::variable::VariableCategory;
+use super::model_view::*;
+use ospf_rust_base::ErrorCode;
+use std::io::Write;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum MpsFormat {
+    // fields separated by whitespace, names without whitespace
+    Free,
+    // fields at fixed columns, names of at most 8 characters
+    Fixed,
+}
+
+// start columns of the 6 fields of a fixed MPS record
+const FIXED_COLUMNS: [usize; 6] = [1, 4, 14, 24, 39, 49];
+
+struct MpsBuffer {
+    format: MpsFormat,
+    text: String,
+}
+
+impl MpsBuffer {
+    fn new(format: MpsFormat) -> Self {
+        Self {
+            format: format,
+            text: String::new(),
+        }
+    }
+
+    fn section(&mut self, header: &str) {
+        self.text.push_str(header);
+        self.text.push('\n');
+    }
+
+    fn number(&self, value: f64) -> String {
+        let ret = format!("{}", value);
+        if self.format == MpsFormat::Free || ret.len() <= 12 {
+            return ret;
+        }
+        let mut precision = 11;
+        loop {
+            let ret = format!("{:.*e}", precision, value);
+            if ret.len() <= 12 || precision == 0 {
+                return ret;
+            }
+            precision -= 1;
+        }
+    }
+
+    fn check_name(&self, name: &str) -> Result<(), ModelError> {
+        let valid = match self.format {
+            MpsFormat::Free => !name.is_empty() && !name.contains(char::is_whitespace),
+            MpsFormat::Fixed => name.len() <= 8,
+        };
+        if valid {
+            Ok(())
+        } else {
+            Err(ModelError::new(
+                ErrorCode::SerializationFailed,
+                format!(
+                    "Name \"{}\" cannot be written in {:?} MPS.",
+                    name, self.format
+                ),
+            ))
+        }
+    }
+
+    // code, name, name, number, name, number; trailing empty fields are left out
+    fn record(&mut self, fields: &[&str]) -> Result<(), ModelError> {
+        for i in [1, 2, 4] {
+            if i < fields.len() && !fields[i].is_empty() && !fields[i].starts_with('\'') {
+                self.check_name(fields[i])?;
+            }
+        }
+        let mut line = String::new();
+        match self.format {
+            MpsFormat::Free => {
+                line.push_str(if fields[0].is_empty() { "   " } else { " " });
+                line.push_str(
+                    &fields
+                        .iter()
+                        .filter(|field| !field.is_empty())
+                        .copied()
+                        .collect::<Vec<&str>>()
+                        .join(" "),
+                );
+            }
+            MpsFormat::Fixed => {
+                for (field, column) in fields.iter().zip(FIXED_COLUMNS) {
+                    if field.is_empty() {
+                        continue;
+                    }
+                    while line.len() < column {
+                        line.push(' ');
+                    }
+                    line.push_str(field);
+                }
+            }
+        }
+        self.text.push_str(&line);
+        self.text.push('\n');
+        Ok(())
+    }
+}
+
+fn is_integer(variable: &ModelVariable) -> bool {
+    match variable.category {
+        VariableCategory::Binary | VariableCategory::Integer | VariableCategory::SemiInteger => {
+            true
+        }
+        _ => false,
+    }
+}
+
+// (bound type, value) records of a column, the default being [0, inf)
+fn bounds(
+    variable: &ModelVariable,
+    name: &str,
+) -> Result<Vec<(&'static str, Option<f64>)>, ModelError> {
+    let (lb, ub) = (variable.lower_bound, variable.upper_bound);
+    let mut ret = Vec::new();
+    if variable.category.is_semi() {
+        if !ub.is_finite() {
+            return Err(ModelError::new(
+                ErrorCode::SerializationFailed,
+                format!("Semi variable {} needs a finite upper bound in MPS.", name),
+            ));
+        }
+        if lb != 0. {
+            ret.push(("LO", Option::Some(lb)));
+        }
+        ret.push(("SC", Option::Some(ub)));
+    } else if variable.category == VariableCategory::Binary && lb == 0. && ub == 1. {
+        ret.push(("BV", Option::None));
+    } else if lb == ub {
+        ret.push(("FX", Option::Some(lb)));
+    } else if lb == f64::NEG_INFINITY && ub == f64::INFINITY {
+        ret.push(("FR", Option::None));
+    } else {
+        if lb == f64::NEG_INFINITY {
+            ret.push(("MI", Option::None));
+        } else if lb != 0. {
+            ret.push(("LO", Option::Some(lb)));
+        }
+        if ub != f64::INFINITY {
+            ret.push(("UP", Option::Some(ub)));
+        } else if is_integer(variable) {
+            // some readers bound integer columns to [0, 1] by default
+            ret.push(("PL", Option::None));
+        }
+    }
+    Ok(ret)
+}
+
+fn mps_text(view: &ModelView, format: MpsFormat) -> Result<String, ModelError> {
+    let model = view.model;
+    let rows = view.rows(true)?;
+    let mut buffer = MpsBuffer::new(format);
+    match format {
+        MpsFormat::Free => buffer.section(&format!("NAME {}", model.name)),
+        MpsFormat::Fixed => buffer.section(&format!("NAME          {}", model.name)),
+    }
+    if view.is_maximum() {
+        buffer.section("OBJSENSE");
+        buffer.section("    MAX");
+    }
+
+    buffer.section("ROWS");
+    buffer.record(&["N", OBJECTIVE_ROW])?;
+    for row in &rows {
+        buffer.record(&[
+            match row.sense {
+                RowSense::LessEqual => "L",
+                RowSense::GreaterEqual => "G",
+                RowSense::Equal => "E",
+            },
+            &row.name,
+        ])?;
+    }
+
+    let mut columns: Vec<Vec<(&str, f64)>> = vec![Vec::new(); model.variables().len()];
+    for monomial in view.objective.linear().monomials() {
+        let column = view.column_of(&monomial.symbol)?;
+        columns[column].push((OBJECTIVE_ROW, monomial.coefficient));
+    }
+    for row in &rows {
+        for monomial in row.linear.monomials() {
+            let column = view.column_of(&monomial.symbol)?;
+            columns[column].push((&row.name, monomial.coefficient));
+        }
+    }
+    buffer.section("COLUMNS");
+    let mut in_marker = false;
+    for (i, variable) in model.variables().iter().enumerate() {
+        if is_integer(variable) != in_marker {
+            in_marker = !in_marker;
+            buffer.record(&[
+                "",
+                "MARKER",
+                "'MARKER'",
+                "",
+                if in_marker { "'INTORG'" } else { "'INTEND'" },
+            ])?;
+        }
+        let name = model.name_of(i);
+        if columns[i].is_empty() {
+            // every column is declared here, even if it has no linear coefficient
+            buffer.record(&["", name, OBJECTIVE_ROW, "0"])?;
+        }
+        for (row, value) in &columns[i] {
+            buffer.record(&["", name, row, &buffer.number(*value)])?;
+        }
+    }
+    if in_marker {
+        buffer.record(&["", "MARKER", "'MARKER'", "", "'INTEND'"])?;
+    }
+
+    buffer.section("RHS");
+    if view.objective.constant() != 0. {
+        // the objective constant is the negative of its right-hand side
+        let value = buffer.number(-view.objective.constant());
+        buffer.record(&["", "RHS", OBJECTIVE_ROW, &value])?;
+    }
+    for row in &rows {
+        if row.rhs != 0. {
+            let value = buffer.number(row.rhs);
+            buffer.record(&["", "RHS", &row.name, &value])?;
+        }
+    }
+
+    if rows.iter().any(|row| row.range.is_some()) {
+        buffer.section("RANGES");
+        for row in &rows {
+            if let Option::Some(range) = row.range {
+                let value = buffer.number(range);
+                buffer.record(&["", "RNG", &row.name, &value])?;
+            }
+        }
+    }
+
+    buffer.section("BOUNDS");
+    for (i, variable) in model.variables().iter().enumerate() {
+        let name = model.name_of(i);
+        for (code, value) in bounds(variable, name)? {
+            match value {
+                Option::Some(value) => {
+                    let value = buffer.number(value);
+                    buffer.record(&[code, "BND", name, &value])?
+                }
+                Option::None => buffer.record(&[code, "BND", name])?,
+            }
+        }
+    }
+
+    if !model.special_ordered_sets().is_empty() {
+        buffer.section("SOS");
+        for (i, set) in model.special_ordered_sets().iter().enumerate() {
+            let name = if set.name.is_empty() {
+                format!("sos{}", i)
+            } else {
+                set.name.clone()
+            };
+            let category = match set.category {
+                SosCategory::Sos1 => "S1",
+                SosCategory::Sos2 => "S2",
+            };
+            buffer.record(&[category, "SOS", &name])?;
+            for (symbol, weight) in set.symbols.iter().zip(set.weights.iter()) {
+                let value = buffer.number(*weight);
+                buffer.record(&["", &name, view.name_of(symbol)?, &value])?;
+            }
+        }
+    }
+
+    // the objective is c^T x + 1/2 x^T Q x, with the lower triangle of Q written
+    if !view.objective.monomials().is_empty() {
+        buffer.section("QUADOBJ");
+        for monomial in view.objective.monomials() {
+            let (name1, name2) = (
+                view.name_of(&monomial.symbol1)?,
+                view.name_of(&monomial.symbol2)?,
+            );
+            let factor = if monomial.is_square() { 2. } else { 1. };
+            let value = buffer.number(factor * monomial.coefficient);
+            buffer.record(&["", name1, name2, &value])?;
+        }
+    }
+
+    // the quadratic terms of a constraint are written as the full symmetric matrix
+    for row in &rows {
+        if row.quadratic.is_empty() {
+            continue;
+        }
+        buffer.check_name(&row.name)?;
+        match format {
+            MpsFormat::Free => buffer.section(&format!("QCMATRIX {}", row.name)),
+            MpsFormat::Fixed => buffer.section(&format!("QCMATRIX   {}", row.name)),
+        }
+        for monomial in &row.quadratic {
+            let (name1, name2) = (
+                view.name_of(&monomial.symbol1)?,
+                view.name_of(&monomial.symbol2)?,
+            );
+            if monomial.is_square() {
+                let value = buffer.number(monomial.coefficient);
+                buffer.record(&["", name1, name2, &value])?;
+            } else {
+                let value = buffer.number(monomial.coefficient / 2.);
+                buffer.record(&["", name1, name2, &value])?;
+                buffer.record(&["", name2, name1, &value])?;
+            }
+        }
+    }
+
+    if rows.iter().any(|row| row.indicator.is_some()) {
+        buffer.section("INDICATORS");
+        for row in &rows {
+            if let Option::Some((indicator, value)) = row.indicator {
+                let value = if value { "1" } else { "0" };
+                buffer.record(&["IF", &row.name, view.name_of(indicator)?, value])?;
+            }
+        }
+    }
+    buffer.section("ENDATA");
+    Ok(buffer.text)
+}
+
+fn write_text<W: Write>(text: &str, writer: &mut W) -> Result<(), ModelError> {
+    writer.write_all(text.as_bytes()).map_err(|error| {
+        ModelError::new(
+            ErrorCode::SerializationFailed,
+            format!("Failed to write the model: {}.", error),
+        )
+    })
+}
+
+pub fn write_mps<W: Write>(
+    model: &LinearMetaModel,
+    format: MpsFormat,
+    writer: &mut W,
+) -> Result<(), ModelError> {
+    write_text(&mps_text(&ModelView::from(model), format)?, writer)
+}
+
+pub fn write_quadratic_mps<W: Write>(
+    model: &QuadraticMetaModel,
+    format: MpsFormat,
+    writer: &mut W,
+) -> Result<(), ModelError> {
+    write_text(&mps_text(&ModelView::from(model), format)?, writer)
+}
+
+#[test]
+fn test_write_mps() {
+    use super::super::constraint::*;
+    use super::super::variable::*;
+
+    let x = UIntVar::new_with_name("x");
+    let y = RealVar::new_with_name("y");
+    let mut model = LinearMetaModel::new_with_name("demo");
+    model.add_var(&x).unwrap();
+    model.add_var(&y).unwrap();
+    model
+        .add_constraint((&x + &y).leq(4.).with_name("cap"))
+        .unwrap();
+    model
+        .add_constraint(
+            Constraint::new_ranged(1., &x - 2. * &y, 3.)
+                .unwrap()
+                .with_name("gap"),
+        )
+        .unwrap();
+    model.minimize(&x - &y + 1.).unwrap();
+
+    let mut buffer: Vec<u8> = Vec::new();
+    write_mps(&model, MpsFormat::Fixed, &mut buffer).unwrap();
+    assert_eq!(
+        String::from_utf8(buffer).unwrap(),
+        "NAME          demo
+ROWS
+ N  obj
+ L  cap
+ G  gap
+COLUMNS
+    MARKER    'MARKER'                 'INTORG'
+    x         obj       1
+    x         cap       1
+    x         gap       1
+    MARKER    'MARKER'                 'INTEND'
+    y         obj       -1
+    y         cap       1
+    y         gap       -2
+RHS
+    RHS       obj       -1
+    RHS       cap       4
+    RHS       gap       1
+RANGES
+    RNG       gap       2
+BOUNDS
+ PL BND       x
+ FR BND       y
+ENDATA
+"
+    );
+
+    let long = RealVar::new_with_name("long_variable");
+    model.add_var(&long).unwrap();
+    let mut buffer: Vec<u8> = Vec::new();
+    assert_eq!(
+        write_mps(&model, MpsFormat::Fixed, &mut buffer)
+            .unwrap_err()
+            .code(),
+        ErrorCode::SerializationFailed
+    );
+    assert!(write_mps(&model, MpsFormat::Free, &mut buffer).is_ok());
+}
+
+#[test]
+fn test_repeated_rows() {
+    use super::super::constraint::*;
+    use super::super::variable::*;
+    use super::lp_writer::write_lp;
+
+    let x = RealVar::new_with_name("x");
+    let mut model = LinearMetaModel::new();
+    model.add_var(&x).unwrap();
+    model.add_constraint((&x).leq(1.).with_name("obj")).unwrap();
+    let mut buffer: Vec<u8> = Vec::new();
+    assert_eq!(
+        write_mps(&model, MpsFormat::Free, &mut buffer)
+            .unwrap_err()
+            .code(),
+        ErrorCode::SerializationFailed
+    );
+    assert!(write_lp(&model, &mut buffer).is_err());
+
+    // the unnamed constraint 1 would be called c1 as well
+    let mut model = LinearMetaModel::new();
+    model.add_var(&x).unwrap();
+    model.add_constraint((&x).leq(1.).with_name("c1")).unwrap();
+    model.add_constraint((&x).geq(0.)).unwrap();
+    assert!(write_mps(&model, MpsFormat::Free, &mut buffer).is_err());
+
+    // ranges are split into x_lb and x_ub in LP files
+    let mut model = LinearMetaModel::new();
+    model.add_var(&x).unwrap();
+    model
+        .add_constraint(Constraint::new_ranged(0., &x, 1.).unwrap().with_name("x"))
+        .unwrap();
+    model
+        .add_constraint((&x).leq(1.).with_name("x_ub"))
+        .unwrap();
+    assert!(write_mps(&model, MpsFormat::Free, &mut buffer).is_ok());
+    assert!(write_lp(&model, &mut buffer).is_err());
+}
@@ -1 +1,6 @@
+pub mod constraint;
+pub mod expression;
+pub mod io;
+pub mod model;
+pub mod symbol;
 pub mod variable;
@@ -0,0 +1,245 @@
+use super::super::constraint::*;
+use super::super::expression::*;
+use super::super::variable::item::new_identifier;
+use super::super::variable::*;
+use super::error::ModelError;
+use super::linear_meta_model::*;
+use ospf_rust_base::ErrorCode;
+use std::collections::HashMap;
+
+// a column of the original model in terms of the expanded one: offset + sum of weight * column
+struct ColumnMapping {
+    offset: f64,
+    terms: Vec<(usize, f64)>,
+}
+
+// Rewrites the variables encoded with VariableEncoding::BinaryExpansion, e.g. a Ternary x
+// becomes x_b0 + 2 * x_b1 with x_b0 + 2 * x_b1 <= 2, so that the model can be handed to
+// pure-binary solvers and heuristics; the other variables are kept as they are.
+pub struct BinaryExpansion {
+    pub model: LinearMetaModel,
+    _mappings: Vec<ColumnMapping>,
+}
+
+fn binary_terms(variable: &ModelVariable) -> Result<(f64, Vec<(VariableSymbol, f64)>), ModelError> {
+    let (lb, ub) = (variable.lower_bound, variable.upper_bound);
+    if variable.category == VariableCategory::Continuous
+        || variable.category.is_semi()
+        || !lb.is_finite()
+        || !ub.is_finite()
+    {
+        return Err(ModelError::new(
+            ErrorCode::OREngineModelingException,
+            format!(
+                "Variable {} should be a bounded integer to be expanded, not {:?} in [{}, {}].",
+                variable.symbol, variable.category, lb, ub
+            ),
+        ));
+    }
+    let (lb, ub) = (lb.ceil(), ub.floor());
+    let identifier = new_identifier();
+    let mut terms = Vec::new();
+    let mut weight = 1.;
+    while weight <= ub - lb {
+        let index = terms.len();
+        let name = format!("{}_b{}", variable.symbol, index);
+        terms.push((VariableSymbol::new(identifier, index, &name), weight));
+        weight *= 2.;
+    }
+    Ok((lb, terms))
+}
+
+fn expand(
+    polynomial: &LinearPolynomial,
+    mappings: &HashMap<(u64, usize), (f64, Vec<(VariableSymbol, f64)>)>,
+) -> LinearPolynomial {
+    let mut ret = LinearPolynomial::new_with_constant(polynomial.constant());
+    for monomial in polynomial.monomials() {
+        match mappings.get(&monomial.symbol.key()) {
+            Option::Some((offset, terms)) => {
+                ret.add_constant(monomial.coefficient * offset);
+                for (symbol, weight) in terms {
+                    ret.add_monomial(LinearMonomial::new(
+                        monomial.coefficient * weight,
+                        symbol.clone(),
+                    ));
+                }
+            }
+            Option::None => ret.add_monomial(monomial.clone()),
+        }
+    }
+    ret
+}
+
+impl BinaryExpansion {
+    // fails if the name of a binary is taken by a variable of the model
+    pub fn new(model: &LinearMetaModel) -> Result<Self, ModelError> {
+        let mut expanded = LinearMetaModel::new_with_name(&model.name);
+        let mut substitutions = HashMap::new();
+        let mut mappings = Vec::new();
+        let mut range_constraints = Vec::new();
+        for variable in model.variables() {
+            if variable.encoding != VariableEncoding::BinaryExpansion {
+                let column = expanded.add_model_variable(variable.clone())?;
+                mappings.push(ColumnMapping {
+                    offset: 0.,
+                    terms: vec![(column, 1.)],
+                });
+                continue;
+            }
+
+            let (offset, terms) = binary_terms(variable)?;
+            let mut mapping = ColumnMapping {
+                offset: offset,
+                terms: Vec::new(),
+            };
+            let mut sum = LinearPolynomial::new();
+            for (symbol, weight) in &terms {
+                let column = expanded.add_model_variable(ModelVariable {
+                    symbol: symbol.clone(),
+                    category: VariableCategory::Binary,
+                    lower_bound: 0.,
+                    upper_bound: 1.,
+                    encoding: VariableEncoding::Native,
+                })?;
+                mapping.terms.push((column, *weight));
+                sum.add_monomial(LinearMonomial::new(*weight, symbol.clone()));
+            }
+            // the binaries may reach 2^n - 1, which can be beyond the range
+            let width = variable.upper_bound.floor() - offset;
+            if 2. * terms.last().map(|(_, weight)| *weight).unwrap_or(0.) - 1. > width {
+                range_constraints.push(
+                    sum.leq(width)
+                        .with_name(&format!("{}_range", variable.symbol)),
+                );
+            }
+            substitutions.insert(variable.symbol.key(), (offset, terms));
+            mappings.push(mapping);
+        }
+
+        for constraint in model.constraints() {
+            let mut constraint = constraint.clone();
+            constraint.lhs = expand(&constraint.lhs, &substitutions);
+            constraint.rhs = expand(&constraint.rhs, &substitutions);
+            expanded.add_constraint(constraint)?;
+        }
+        for constraint in range_constraints {
+            expanded.add_constraint(constraint)?;
+        }
+        for set in model.special_ordered_sets() {
+            if set
+                .symbols
+                .iter()
+                .any(|symbol| substitutions.contains_key(&symbol.key()))
+            {
+                return Err(ModelError::new(
+                    ErrorCode::OREngineModelingException,
+                    format!("Members of {} cannot be expanded.", set),
+                ));
+            }
+            expanded.add_special_ordered_set(set.clone())?;
+        }
+        for constraint in model.indicator_constraints() {
+            if substitutions.contains_key(&constraint.indicator.key()) {
+                return Err(ModelError::new(
+                    ErrorCode::OREngineModelingException,
+                    format!("Indicator of {} cannot be expanded.", constraint),
+                ));
+            }
+            let mut constraint = constraint.clone();
+            constraint.constraint.lhs = expand(&constraint.constraint.lhs, &substitutions);
+            constraint.constraint.rhs = expand(&constraint.constraint.rhs, &substitutions);
+            expanded.add_indicator_constraint(constraint)?;
+        }
+        let objective = expand(model.objective(), &substitutions);
+        match model.object_category() {
+            ObjectCategory::Minimum => expanded.minimize(objective),
+            ObjectCategory::Maximum => expanded.maximize(objective),
+        }?;
+        Ok(Self {
+            model: expanded,
+            _mappings: mappings,
+        })
+    }
+
+    // values of the original columns from a solution of the expanded model
+    pub fn decode(&self, solution: &[f64]) -> Vec<f64> {
+        self._mappings
+            .iter()
+            .map(|mapping| {
+                mapping.offset
+                    + mapping
+                        .terms
+                        .iter()
+                        .map(|(column, weight)| weight * solution[*column])
+                        .sum::<f64>()
+            })
+            .collect()
+    }
+}
+
+#[test]
+fn test_binary_expansion() {
+    let x = TerVar::new_with_name("x");
+    let y = BTerVar::new_with_name("y");
+    let z = PctVar::new_with_name("z");
+    let mut model = LinearMetaModel::new();
+    model
+        .add_var_with_encoding(&x, VariableEncoding::BinaryExpansion)
+        .unwrap();
+    model
+        .add_var_with_encoding(&y, VariableEncoding::BinaryExpansion)
+        .unwrap();
+    model.add_var(&z).unwrap();
+    model.add_constraint((&x + &y).geq(1.)).unwrap();
+    model.minimize(&x - &y + &z).unwrap();
+
+    assert_eq!(
+        model
+            .variables()
+            .iter()
+            .map(|variable| (
+                variable.category,
+                variable.lower_bound,
+                variable.upper_bound
+            ))
+            .collect::<Vec<_>>(),
+        vec![
+            (VariableCategory::Integer, 0., 2.),
+            (VariableCategory::Integer, -1., 1.),
+            (VariableCategory::Continuous, 0., 1.)
+        ]
+    );
+
+    let expansion = BinaryExpansion::new(&model).unwrap();
+    assert_eq!(expansion.model.variables().len(), 5);
+    assert_eq!(expansion.model.constraints().len(), 3);
+    assert_eq!(
+        format!("{}", expansion.model.objective()),
+        "x_b0 + 2 * x_b1 - y_b0 - 2 * y_b1 + z + 1"
+    );
+    assert_eq!(expansion.decode(&[0., 1., 1., 0., 0.5]), vec![2., 0., 0.5]);
+}
+
+#[test]
+fn test_binary_expansion_unsupported() {
+    let x = RealVar::new_with_name("x");
+    let mut model = LinearMetaModel::new();
+    model
+        .add_var_with_encoding(&x, VariableEncoding::BinaryExpansion)
+        .unwrap();
+    assert_eq!(
+        BinaryExpansion::new(&model).err().unwrap().code(),
+        ErrorCode::OREngineModelingException
+    );
+
+    let y = TerVar::new_with_name("y");
+    let mut model = LinearMetaModel::new();
+    model
+        .add_var_with_encoding(&y, VariableEncoding::BinaryExpansion)
+        .unwrap();
+    model
+        .add_special_ordered_set(SpecialOrderedSet::new_sos1([&y]))
+        .unwrap();
+    assert!(BinaryExpansion::new(&model).is_err());
+}
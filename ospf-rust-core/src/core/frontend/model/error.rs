@@ -0,0 +1 @@
+pub use crate::core::error::Error as ModelError;
@@ -0,0 +1,388 @@
+use super::super::constraint::*;
+use super::super::expression::*;
+use super::super::symbol::IntermediateSymbol;
+use super::super::variable::*;
+use super::error::ModelError;
+use super::token_table::TokenTable;
+use ospf_rust_base::ErrorCode;
+use std::fmt;
+use std::fmt::Display;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ObjectCategory {
+    Minimum,
+    Maximum,
+}
+
+impl Display for ObjectCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ObjectCategory::Minimum => write!(f, "minimize"),
+            ObjectCategory::Maximum => write!(f, "maximize"),
+        }
+    }
+}
+
+// how a variable is handed to the solver: as a column of its own category, or as a
+// weighted sum of binary columns, see BinaryExpansion
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum VariableEncoding {
+    Native,
+    BinaryExpansion,
+}
+
+#[derive(Clone, Debug)]
+pub struct ModelVariable {
+    pub symbol: VariableSymbol,
+    pub category: VariableCategory,
+    pub lower_bound: f64,
+    pub upper_bound: f64,
+    pub encoding: VariableEncoding,
+}
+
+impl ModelVariable {
+    pub fn new<V: VariableItem>(item: &V) -> Self {
+        Self {
+            symbol: VariableSymbol::from(item),
+            category: V::Type::category(),
+            lower_bound: item.range().lower_bound(),
+            upper_bound: item.range().upper_bound(),
+            encoding: VariableEncoding::Native,
+        }
+    }
+
+    pub fn with_encoding(mut self, encoding: VariableEncoding) -> Self {
+        self.encoding = encoding;
+        self
+    }
+
+    pub fn empty(&self) -> bool {
+        !(self.lower_bound <= self.upper_bound)
+    }
+}
+
+impl Display for ModelVariable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} <= {} <= {}, {:?}",
+            self.lower_bound, self.symbol, self.upper_bound, self.category
+        )
+    }
+}
+
+pub struct LinearMetaModel {
+    pub name: String,
+    _variables: Vec<ModelVariable>,
+    _tokens: TokenTable,
+    _constraints: Vec<Constraint>,
+    _special_ordered_sets: Vec<SpecialOrderedSet>,
+    _indicator_constraints: Vec<IndicatorConstraint>,
+    _objective: LinearPolynomial,
+    _object_category: ObjectCategory,
+}
+
+impl LinearMetaModel {
+    pub fn new() -> Self {
+        Self::new_with_name("")
+    }
+
+    pub fn new_with_name(_name: &str) -> Self {
+        Self {
+            name: _name.to_string(),
+            _variables: Vec::new(),
+            _tokens: TokenTable::new(),
+            _constraints: Vec::new(),
+            _special_ordered_sets: Vec::new(),
+            _indicator_constraints: Vec::new(),
+            _objective: LinearPolynomial::new(),
+            _object_category: ObjectCategory::Minimum,
+        }
+    }
+
+    pub fn add_var<V: VariableItem>(&mut self, item: &V) -> Result<usize, ModelError> {
+        self.add_model_variable(ModelVariable::new(item))
+    }
+
+    pub fn add_var_with_encoding<V: VariableItem>(
+        &mut self,
+        item: &V,
+        encoding: VariableEncoding,
+    ) -> Result<usize, ModelError> {
+        self.add_model_variable(ModelVariable::new(item).with_encoding(encoding))
+    }
+
+    pub fn add_vars<'a, V: VariableItem + 'a, I: IntoIterator<Item = &'a V>>(
+        &mut self,
+        items: I,
+    ) -> Result<Vec<usize>, ModelError> {
+        items.into_iter().map(|item| self.add_var(item)).collect()
+    }
+
+    // an item is registered once, and its name should not be taken by another one
+    pub fn add_model_variable(&mut self, variable: ModelVariable) -> Result<usize, ModelError> {
+        let column = self._tokens.add(&variable.symbol)?;
+        self._variables.push(variable);
+        Ok(column)
+    }
+
+    // the variables of a constraint should be registered before it, as their category
+    // and bounds are not known to the model otherwise
+    pub fn add_constraint(&mut self, constraint: Constraint) -> Result<usize, ModelError> {
+        self.check_symbols(
+            constraint
+                .lhs
+                .monomials()
+                .iter()
+                .chain(constraint.rhs.monomials())
+                .map(|monomial| &monomial.symbol),
+            &constraint,
+        )?;
+        let row = self._constraints.len();
+        self._constraints.push(constraint);
+        Ok(row)
+    }
+
+    pub fn add_special_ordered_set(&mut self, set: SpecialOrderedSet) -> Result<usize, ModelError> {
+        self.check_symbols(&set.symbols, &set)?;
+        let index = self._special_ordered_sets.len();
+        self._special_ordered_sets.push(set);
+        Ok(index)
+    }
+
+    pub fn add_indicator_constraint(
+        &mut self,
+        constraint: IndicatorConstraint,
+    ) -> Result<usize, ModelError> {
+        self.check_symbols(
+            std::iter::once(&constraint.indicator).chain(
+                constraint
+                    .constraint
+                    .lhs
+                    .monomials()
+                    .iter()
+                    .chain(constraint.constraint.rhs.monomials())
+                    .map(|monomial| &monomial.symbol),
+            ),
+            &constraint,
+        )?;
+        let index = self._indicator_constraints.len();
+        self._indicator_constraints.push(constraint);
+        Ok(index)
+    }
+
+    pub(crate) fn check_symbols<'a, I: IntoIterator<Item = &'a VariableSymbol>, T: Display>(
+        &self,
+        symbols: I,
+        owner: &T,
+    ) -> Result<(), ModelError> {
+        for symbol in symbols {
+            if self.column_of(symbol).is_none() {
+                return Err(ModelError::new(
+                    ErrorCode::OREngineModelingException,
+                    format!("Variable {} of {} is not registered.", symbol, owner),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    // the auxiliary variables and constraints of a symbol are registered once,
+    // the returned expression stands for its value
+    pub fn add_symbol<S: IntermediateSymbol>(
+        &mut self,
+        symbol: &S,
+    ) -> Result<LinearPolynomial, ModelError> {
+        if self.column_of(symbol.symbol()).is_none() {
+            symbol.register(self)?;
+        }
+        Ok(symbol.expression())
+    }
+
+    pub fn minimize<Expr: Into<LinearPolynomial>>(
+        &mut self,
+        objective: Expr,
+    ) -> Result<(), ModelError> {
+        self.set_objective(objective.into(), ObjectCategory::Minimum)
+    }
+
+    pub fn maximize<Expr: Into<LinearPolynomial>>(
+        &mut self,
+        objective: Expr,
+    ) -> Result<(), ModelError> {
+        self.set_objective(objective.into(), ObjectCategory::Maximum)
+    }
+
+    // backends only load the objective terms of the registered columns
+    fn set_objective(
+        &mut self,
+        objective: LinearPolynomial,
+        category: ObjectCategory,
+    ) -> Result<(), ModelError> {
+        self.check_symbols(
+            objective
+                .monomials()
+                .iter()
+                .map(|monomial| &monomial.symbol),
+            &"the objective",
+        )?;
+        self._objective = objective;
+        self._object_category = category;
+        Ok(())
+    }
+
+    pub fn variables(&self) -> &[ModelVariable] {
+        &self._variables
+    }
+
+    pub fn constraints(&self) -> &[Constraint] {
+        &self._constraints
+    }
+
+    pub fn special_ordered_sets(&self) -> &[SpecialOrderedSet] {
+        &self._special_ordered_sets
+    }
+
+    pub fn indicator_constraints(&self) -> &[IndicatorConstraint] {
+        &self._indicator_constraints
+    }
+
+    pub fn objective(&self) -> &LinearPolynomial {
+        &self._objective
+    }
+
+    pub fn object_category(&self) -> ObjectCategory {
+        self._object_category
+    }
+
+    pub fn tokens(&self) -> &TokenTable {
+        &self._tokens
+    }
+
+    pub fn column_of(&self, symbol: &VariableSymbol) -> Option<usize> {
+        self._tokens.column_of(symbol)
+    }
+
+    pub fn name_of(&self, column: usize) -> &str {
+        self._tokens.name_of(column)
+    }
+
+    // the range an expression can take within the bounds of the variables, semi
+    // variables may be 0 as well; infinite when a variable is unbounded or unknown
+    pub fn bounds_of(&self, expression: &LinearPolynomial) -> (f64, f64) {
+        let mut lb = expression.constant();
+        let mut ub = expression.constant();
+        for monomial in expression.monomials() {
+            let (lower, upper) = match self.column_of(&monomial.symbol) {
+                Option::Some(column) => {
+                    let variable = &self._variables[column];
+                    if variable.category.is_semi() {
+                        (variable.lower_bound.min(0.), variable.upper_bound.max(0.))
+                    } else {
+                        (variable.lower_bound, variable.upper_bound)
+                    }
+                }
+                Option::None => (f64::NEG_INFINITY, f64::INFINITY),
+            };
+            if monomial.coefficient > 0. {
+                lb += monomial.coefficient * lower;
+                ub += monomial.coefficient * upper;
+            } else if monomial.coefficient < 0. {
+                lb += monomial.coefficient * upper;
+                ub += monomial.coefficient * lower;
+            }
+        }
+        (lb, ub)
+    }
+
+    pub fn has_binary_expansion(&self) -> bool {
+        self._variables
+            .iter()
+            .any(|variable| variable.encoding == VariableEncoding::BinaryExpansion)
+    }
+
+    // whether branching is needed, which special ordered sets need as well
+    pub fn is_integer(&self) -> bool {
+        !self._special_ordered_sets.is_empty()
+            || self
+                ._variables
+                .iter()
+                .any(|variable| variable.category != VariableCategory::Continuous)
+    }
+}
+
+impl Display for LinearMetaModel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{} {}", self._object_category, self._objective)?;
+        writeln!(f, "subject to")?;
+        for constraint in &self._constraints {
+            writeln!(f, "  {}", constraint)?;
+        }
+        for set in &self._special_ordered_sets {
+            writeln!(f, "  {}", set)?;
+        }
+        for constraint in &self._indicator_constraints {
+            writeln!(f, "  {}", constraint)?;
+        }
+        writeln!(f, "variables")?;
+        for variable in &self._variables {
+            writeln!(f, "  {}", variable)?;
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn test_bounds_of() {
+    let x = TerVar::new_with_name("x");
+    let y = BTerVar::new_with_name("y");
+    let b = BinVar::new_with_name("b");
+    let mut model = LinearMetaModel::new();
+    model.add_vars([&x]).unwrap();
+    model.add_var(&y).unwrap();
+    model.add_var(&b).unwrap();
+    assert_eq!(
+        model.add_var(&x).unwrap_err().code(),
+        ospf_rust_base::ErrorCode::TokenExisted
+    );
+    assert_eq!(model.bounds_of(&(2. * &x - &y + 1.)), (0., 6.));
+
+    let constraint = IndicatorConstraint::new(&b, (&x + &y).leq(1.));
+    assert_eq!(
+        model.bounds_of(&constraint.constraint.expression()),
+        (-1., 3.)
+    );
+    model.add_indicator_constraint(constraint).unwrap();
+    assert_eq!(
+        format!("{}", model.indicator_constraints()[0]),
+        "b == 1 -> x + y <= 1"
+    );
+}
+
+#[test]
+fn test_unregistered_variables() {
+    let x = BinVar::new_with_name("x");
+    let y = BinVar::new_with_name("y");
+    let mut model = LinearMetaModel::new();
+    model.add_var(&x).unwrap();
+    assert_eq!(
+        model.add_constraint((&x + &y).leq(1.)).unwrap_err().code(),
+        ospf_rust_base::ErrorCode::OREngineModelingException
+    );
+    assert!(model
+        .add_special_ordered_set(SpecialOrderedSet::new_sos1([&x, &y]))
+        .is_err());
+    assert!(model
+        .add_indicator_constraint(IndicatorConstraint::new(&y, (&x).leq(0.)))
+        .is_err());
+    assert!(model.maximize(&x + &y).is_err());
+    assert!(model.constraints().is_empty());
+    assert!(model.objective().monomials().is_empty());
+    assert_eq!(model.add_constraint((&x).leq(1.)).unwrap(), 0);
+
+    let mut model = super::quadratic_meta_model::QuadraticMetaModel::new();
+    model.add_var(&x).unwrap();
+    assert!(model.minimize(&x * &y).is_err());
+    assert!(model.minimize(&x * &x + &y).is_err());
+    assert!(model.minimize(&x * &x + &x).is_ok());
+}
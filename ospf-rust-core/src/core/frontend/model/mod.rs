@@ -0,0 +1,11 @@
+pub mod binary_expansion;
+pub mod error;
+pub mod linear_meta_model;
+pub mod quadratic_meta_model;
+pub mod token_table;
+
+pub use binary_expansion::BinaryExpansion;
+pub use error::ModelError;
+pub use linear_meta_model::{LinearMetaModel, ModelVariable, ObjectCategory, VariableEncoding};
+pub use quadratic_meta_model::QuadraticMetaModel;
+pub use token_table::{Token, TokenTable};
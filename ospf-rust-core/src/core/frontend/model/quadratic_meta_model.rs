@@ -0,0 +1,206 @@
+use super::super::constraint::*;
+use super::super::expression::*;
+use super::super::symbol::IntermediateSymbol;
+use super::super::variable::*;
+use super::error::ModelError;
+use super::linear_meta_model::*;
+use std::fmt;
+use std::fmt::Display;
+
+pub struct QuadraticMetaModel {
+    // variables, linear constraints and the linear part of the objective
+    _linear: LinearMetaModel,
+    _quadratic_constraints: Vec<QuadraticConstraint>,
+    _objective: QuadraticPolynomial,
+}
+
+impl QuadraticMetaModel {
+    pub fn new() -> Self {
+        Self::new_with_name("")
+    }
+
+    pub fn new_with_name(_name: &str) -> Self {
+        Self {
+            _linear: LinearMetaModel::new_with_name(_name),
+            _quadratic_constraints: Vec::new(),
+            _objective: QuadraticPolynomial::new(),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self._linear.name
+    }
+
+    pub fn add_var<V: VariableItem>(&mut self, item: &V) -> Result<usize, ModelError> {
+        self._linear.add_var(item)
+    }
+
+    pub fn add_vars<'a, V: VariableItem + 'a, I: IntoIterator<Item = &'a V>>(
+        &mut self,
+        items: I,
+    ) -> Result<Vec<usize>, ModelError> {
+        self._linear.add_vars(items)
+    }
+
+    pub fn add_model_variable(&mut self, variable: ModelVariable) -> Result<usize, ModelError> {
+        self._linear.add_model_variable(variable)
+    }
+
+    pub fn add_constraint(&mut self, constraint: Constraint) -> Result<usize, ModelError> {
+        self._linear.add_constraint(constraint)
+    }
+
+    pub fn add_quadratic_constraint(
+        &mut self,
+        constraint: QuadraticConstraint,
+    ) -> Result<usize, ModelError> {
+        let quadratic = constraint
+            .lhs
+            .monomials()
+            .iter()
+            .chain(constraint.rhs.monomials())
+            .flat_map(|monomial| [&monomial.symbol1, &monomial.symbol2]);
+        let linear = constraint
+            .lhs
+            .linear()
+            .monomials()
+            .iter()
+            .chain(constraint.rhs.linear().monomials())
+            .map(|monomial| &monomial.symbol);
+        self._linear
+            .check_symbols(quadratic.chain(linear), &constraint)?;
+        let row = self._quadratic_constraints.len();
+        self._quadratic_constraints.push(constraint);
+        Ok(row)
+    }
+
+    pub fn add_special_ordered_set(&mut self, set: SpecialOrderedSet) -> Result<usize, ModelError> {
+        self._linear.add_special_ordered_set(set)
+    }
+
+    pub fn add_indicator_constraint(
+        &mut self,
+        constraint: IndicatorConstraint,
+    ) -> Result<usize, ModelError> {
+        self._linear.add_indicator_constraint(constraint)
+    }
+
+    pub fn add_symbol<S: IntermediateSymbol>(
+        &mut self,
+        symbol: &S,
+    ) -> Result<LinearPolynomial, ModelError> {
+        self._linear.add_symbol(symbol)
+    }
+
+    pub fn minimize<Expr: Into<QuadraticPolynomial>>(
+        &mut self,
+        objective: Expr,
+    ) -> Result<(), ModelError> {
+        let objective = objective.into();
+        self.check_quadratic_symbols(&objective)?;
+        self._linear.minimize(objective.linear().clone())?;
+        self._objective = objective;
+        Ok(())
+    }
+
+    pub fn maximize<Expr: Into<QuadraticPolynomial>>(
+        &mut self,
+        objective: Expr,
+    ) -> Result<(), ModelError> {
+        let objective = objective.into();
+        self.check_quadratic_symbols(&objective)?;
+        self._linear.maximize(objective.linear().clone())?;
+        self._objective = objective;
+        Ok(())
+    }
+
+    fn check_quadratic_symbols(&self, objective: &QuadraticPolynomial) -> Result<(), ModelError> {
+        self._linear.check_symbols(
+            objective
+                .monomials()
+                .iter()
+                .flat_map(|monomial| [&monomial.symbol1, &monomial.symbol2]),
+            &"the objective",
+        )
+    }
+
+    // backends load this part as usual and add the quadratic terms on top of it
+    pub fn linear(&self) -> &LinearMetaModel {
+        &self._linear
+    }
+
+    pub fn variables(&self) -> &[ModelVariable] {
+        self._linear.variables()
+    }
+
+    pub fn constraints(&self) -> &[Constraint] {
+        self._linear.constraints()
+    }
+
+    pub fn quadratic_constraints(&self) -> &[QuadraticConstraint] {
+        &self._quadratic_constraints
+    }
+
+    pub fn special_ordered_sets(&self) -> &[SpecialOrderedSet] {
+        self._linear.special_ordered_sets()
+    }
+
+    pub fn indicator_constraints(&self) -> &[IndicatorConstraint] {
+        self._linear.indicator_constraints()
+    }
+
+    pub fn objective(&self) -> &QuadraticPolynomial {
+        &self._objective
+    }
+
+    pub fn object_category(&self) -> ObjectCategory {
+        self._linear.object_category()
+    }
+
+    pub fn column_of(&self, symbol: &VariableSymbol) -> Option<usize> {
+        self._linear.column_of(symbol)
+    }
+
+    pub fn name_of(&self, column: usize) -> &str {
+        self._linear.name_of(column)
+    }
+
+    pub fn is_integer(&self) -> bool {
+        self._linear.is_integer()
+    }
+}
+
+impl From<LinearMetaModel> for QuadraticMetaModel {
+    fn from(model: LinearMetaModel) -> Self {
+        let objective = QuadraticPolynomial::from(model.objective().clone());
+        Self {
+            _linear: model,
+            _quadratic_constraints: Vec::new(),
+            _objective: objective,
+        }
+    }
+}
+
+impl Display for QuadraticMetaModel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{} {}", self.object_category(), self._objective)?;
+        writeln!(f, "subject to")?;
+        for constraint in self.constraints() {
+            writeln!(f, "  {}", constraint)?;
+        }
+        for constraint in &self._quadratic_constraints {
+            writeln!(f, "  {}", constraint)?;
+        }
+        for set in self.special_ordered_sets() {
+            writeln!(f, "  {}", set)?;
+        }
+        for constraint in self.indicator_constraints() {
+            writeln!(f, "  {}", constraint)?;
+        }
+        writeln!(f, "variables")?;
+        for variable in self.variables() {
+            writeln!(f, "  {}", variable)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,147 @@
+use super::super::expression::VariableSymbol;
+use super::error::ModelError;
+use ospf_rust_base::ErrorCode;
+use std::collections::HashMap;
+
+#[derive(Clone, Debug)]
+pub struct Token {
+    pub symbol: VariableSymbol,
+    pub column: usize,
+    // the name handed to the solvers and writers, x_<identifier>_<index> for unnamed items,
+    // suffixed by _<k> if it is taken by another item
+    pub name: String,
+}
+
+// columns and names of the registered variables, each item (identifier + index)
+// and each name taken once
+#[derive(Clone, Debug)]
+pub struct TokenTable {
+    _tokens: Vec<Token>,
+    _columns: HashMap<(u64, usize), usize>,
+    _names: HashMap<String, usize>,
+}
+
+impl TokenTable {
+    pub fn new() -> Self {
+        Self {
+            _tokens: Vec::new(),
+            _columns: HashMap::new(),
+            _names: HashMap::new(),
+        }
+    }
+
+    pub fn add(&mut self, symbol: &VariableSymbol) -> Result<usize, ModelError> {
+        if let Option::Some(column) = self._columns.get(&symbol.key()) {
+            return Err(ModelError::new(
+                ErrorCode::TokenExisted,
+                format!("{} is already registered as column {}.", symbol, column),
+            ));
+        }
+        // a user name takes precedence over a generated one, which is made unique instead
+        let name = if symbol.name.is_empty() {
+            self.unique_name(symbol.to_string())
+        } else {
+            if let Option::Some(column) = self._names.get(&symbol.name).copied() {
+                if !self._tokens[column].symbol.name.is_empty() {
+                    return Err(ModelError::new(
+                        ErrorCode::SymbolRepetitive,
+                        format!(
+                            "Name {} of item {:?} is already taken by item {:?}.",
+                            symbol.name,
+                            symbol.key(),
+                            self._tokens[column].symbol.key()
+                        ),
+                    ));
+                }
+                let generated = self.unique_name(symbol.name.clone());
+                self._names.remove(&symbol.name);
+                self._names.insert(generated.clone(), column);
+                self._tokens[column].name = generated;
+            }
+            symbol.name.clone()
+        };
+        let column = self._tokens.len();
+        self._columns.insert(symbol.key(), column);
+        self._names.insert(name.clone(), column);
+        self._tokens.push(Token {
+            symbol: symbol.clone(),
+            column: column,
+            name: name,
+        });
+        Ok(column)
+    }
+
+    fn unique_name(&self, name: String) -> String {
+        if !self._names.contains_key(&name) {
+            return name;
+        }
+        (1..)
+            .map(|k| format!("{}_{}", name, k))
+            .find(|name| !self._names.contains_key(name))
+            .unwrap()
+    }
+
+    pub fn find(&self, symbol: &VariableSymbol) -> Option<&Token> {
+        self._columns
+            .get(&symbol.key())
+            .map(|column| &self._tokens[*column])
+    }
+
+    pub fn find_by_name(&self, name: &str) -> Option<&Token> {
+        self._names.get(name).map(|column| &self._tokens[*column])
+    }
+
+    pub fn column_of(&self, symbol: &VariableSymbol) -> Option<usize> {
+        self._columns.get(&symbol.key()).copied()
+    }
+
+    pub fn name_of(&self, column: usize) -> &str {
+        &self._tokens[column].name
+    }
+
+    pub fn tokens(&self) -> &[Token] {
+        &self._tokens
+    }
+
+    pub fn len(&self) -> usize {
+        self._tokens.len()
+    }
+}
+
+#[test]
+fn test_token_table() {
+    let mut table = TokenTable::new();
+    let x = VariableSymbol::new(1, 0, "x");
+    let y = VariableSymbol::new(1, 1, "");
+    assert_eq!(table.add(&x).unwrap(), 0);
+    assert_eq!(table.add(&y).unwrap(), 1);
+    assert_eq!(table.name_of(1), "x_1_1");
+    assert_eq!(table.find_by_name("x").unwrap().column, 0);
+
+    assert_eq!(
+        table
+            .add(&VariableSymbol::new(1, 0, "z"))
+            .unwrap_err()
+            .code(),
+        ErrorCode::TokenExisted
+    );
+    assert_eq!(
+        table
+            .add(&VariableSymbol::new(2, 0, "x"))
+            .unwrap_err()
+            .code(),
+        ErrorCode::SymbolRepetitive
+    );
+    assert_eq!(table.len(), 2);
+
+    // names generated for unnamed items give way to the user names
+    let z = VariableSymbol::new(3, 0, "x_1_1");
+    assert_eq!(table.add(&z).unwrap(), 2);
+    assert_eq!(table.name_of(1), "x_1_1_1");
+    assert_eq!(table.name_of(2), "x_1_1");
+    assert_eq!(table.find_by_name("x_1_1").unwrap().column, 2);
+    assert_eq!(table.find_by_name("x_1_1_1").unwrap().column, 1);
+    table.add(&VariableSymbol::new(4, 0, "x_1_4")).unwrap();
+    assert_eq!(table.add(&VariableSymbol::new(1, 4, "")).unwrap(), 4);
+    assert_eq!(table.name_of(4), "x_1_4_1");
+}
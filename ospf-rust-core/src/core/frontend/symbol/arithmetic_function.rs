@@ -0,0 +1,458 @@
+use super::super::constraint::LinearComparison;
+use super::super::expression::*;
+use super::super::model::{LinearMetaModel, ModelError};
+use super::super::variable::VariableCategory;
+use super::intermediate_symbol::*;
+use std::fmt;
+use std::fmt::Display;
+
+pub struct Abs {
+    _symbol: VariableSymbol,
+    pub argument: LinearPolynomial,
+}
+
+impl Abs {
+    pub fn new<Expr: Into<LinearPolynomial>>(name: &str, argument: Expr) -> Self {
+        Self {
+            _symbol: new_symbol(name),
+            argument: argument.into(),
+        }
+    }
+}
+
+impl IntermediateSymbol for Abs {
+    fn symbol(&self) -> &VariableSymbol {
+        &self._symbol
+    }
+
+    // x = p - n and y = p + n, with a binary b letting only one of p <= ub * b
+    // and n <= -lb * (1 - b) be nonzero; a sign fixed by the bounds needs none of them
+    fn register(&self, model: &mut LinearMetaModel) -> Result<(), ModelError> {
+        let symbol = &self._symbol;
+        let (lb, ub) = model.bounds_of(&self.argument);
+        if lb >= 0. {
+            let y = add_variable(model, symbol.clone(), VariableCategory::Continuous, lb, ub)?;
+            model.add_constraint(y.equal(self.argument.clone()).with_name(&symbol.name))?;
+            return Ok(());
+        }
+        if ub <= 0. {
+            let y = add_variable(
+                model,
+                symbol.clone(),
+                VariableCategory::Continuous,
+                -ub,
+                -lb,
+            )?;
+            model.add_constraint(y.equal(-self.argument.clone()).with_name(&symbol.name))?;
+            return Ok(());
+        }
+
+        let (lb, ub) = finite_bounds_of(model, &self.argument, symbol)?;
+        let y = add_variable(
+            model,
+            symbol.clone(),
+            VariableCategory::Continuous,
+            0.,
+            ub.max(-lb),
+        )?;
+        let p = add_variable(
+            model,
+            auxiliary_symbol(symbol, 1, "pos"),
+            VariableCategory::Continuous,
+            0.,
+            ub,
+        )?;
+        let n = add_variable(
+            model,
+            auxiliary_symbol(symbol, 2, "neg"),
+            VariableCategory::Continuous,
+            0.,
+            -lb,
+        )?;
+        let b = add_variable(
+            model,
+            auxiliary_symbol(symbol, 3, "sign"),
+            VariableCategory::Binary,
+            0.,
+            1.,
+        )?;
+        model.add_constraint(
+            (p.clone() - n.clone())
+                .equal(self.argument.clone())
+                .with_name(&format!("{}_split", symbol)),
+        )?;
+        model.add_constraint(
+            y.equal(p.clone() + n.clone())
+                .with_name(&format!("{}_value", symbol)),
+        )?;
+        model.add_constraint(
+            (p - b.clone() * ub)
+                .leq(0.)
+                .with_name(&format!("{}_pos", symbol)),
+        )?;
+        model.add_constraint((n + b * -lb).leq(-lb).with_name(&format!("{}_neg", symbol)))?;
+        Ok(())
+    }
+}
+
+impl Display for Abs {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} = abs({})", self._symbol, self.argument)
+    }
+}
+
+// y is not below any argument, and not above the one picked by the binaries,
+// with big-Ms from the bounds of the arguments
+fn register_extremum(
+    model: &mut LinearMetaModel,
+    symbol: &VariableSymbol,
+    arguments: &[LinearPolynomial],
+    maximum: bool,
+) -> Result<(), ModelError> {
+    let bounds: Vec<(f64, f64)> = arguments
+        .iter()
+        .map(|argument| finite_bounds_of(model, argument, symbol))
+        .collect::<Result<_, _>>()?;
+    let (lb, ub) = if maximum {
+        (
+            bounds
+                .iter()
+                .map(|(lb, _)| *lb)
+                .fold(f64::NEG_INFINITY, f64::max),
+            bounds
+                .iter()
+                .map(|(_, ub)| *ub)
+                .fold(f64::NEG_INFINITY, f64::max),
+        )
+    } else {
+        (
+            bounds
+                .iter()
+                .map(|(lb, _)| *lb)
+                .fold(f64::INFINITY, f64::min),
+            bounds
+                .iter()
+                .map(|(_, ub)| *ub)
+                .fold(f64::INFINITY, f64::min),
+        )
+    };
+    let y = add_variable(model, symbol.clone(), VariableCategory::Continuous, lb, ub)?;
+    if arguments.len() == 1 {
+        model.add_constraint(y.equal(arguments[0].clone()).with_name(&symbol.name))?;
+        return Ok(());
+    }
+
+    let mut selection = LinearPolynomial::new();
+    for (i, (argument, (arg_lb, arg_ub))) in arguments.iter().zip(bounds).enumerate() {
+        let b = add_variable(
+            model,
+            auxiliary_symbol(symbol, i + 1, &format!("select{}", i)),
+            VariableCategory::Binary,
+            0.,
+            1.,
+        )?;
+        selection += b.clone();
+        let difference = y.clone() - argument.clone();
+        if maximum {
+            let big_m = ub - arg_lb;
+            model.add_constraint(
+                difference
+                    .clone()
+                    .geq(0.)
+                    .with_name(&format!("{}_lb{}", symbol, i)),
+            )?;
+            model.add_constraint(
+                (difference + b * big_m)
+                    .leq(big_m)
+                    .with_name(&format!("{}_ub{}", symbol, i)),
+            )?;
+        } else {
+            let big_m = arg_ub - lb;
+            model.add_constraint(
+                difference
+                    .clone()
+                    .leq(0.)
+                    .with_name(&format!("{}_ub{}", symbol, i)),
+            )?;
+            model.add_constraint(
+                (difference - b * big_m)
+                    .geq(-big_m)
+                    .with_name(&format!("{}_lb{}", symbol, i)),
+            )?;
+        }
+    }
+    model.add_constraint(selection.equal(1.).with_name(&format!("{}_select", symbol)))?;
+    Ok(())
+}
+
+pub struct Max {
+    _symbol: VariableSymbol,
+    pub arguments: Vec<LinearPolynomial>,
+}
+
+impl Max {
+    pub fn new<Expr: Into<LinearPolynomial>, I: IntoIterator<Item = Expr>>(
+        name: &str,
+        arguments: I,
+    ) -> Result<Self, ModelError> {
+        Ok(Self {
+            _symbol: new_symbol(name),
+            arguments: arguments_of(name, arguments)?,
+        })
+    }
+}
+
+impl IntermediateSymbol for Max {
+    fn symbol(&self) -> &VariableSymbol {
+        &self._symbol
+    }
+
+    fn register(&self, model: &mut LinearMetaModel) -> Result<(), ModelError> {
+        register_extremum(model, &self._symbol, &self.arguments, true)
+    }
+}
+
+pub struct Min {
+    _symbol: VariableSymbol,
+    pub arguments: Vec<LinearPolynomial>,
+}
+
+impl Min {
+    pub fn new<Expr: Into<LinearPolynomial>, I: IntoIterator<Item = Expr>>(
+        name: &str,
+        arguments: I,
+    ) -> Result<Self, ModelError> {
+        Ok(Self {
+            _symbol: new_symbol(name),
+            arguments: arguments_of(name, arguments)?,
+        })
+    }
+}
+
+impl IntermediateSymbol for Min {
+    fn symbol(&self) -> &VariableSymbol {
+        &self._symbol
+    }
+
+    fn register(&self, model: &mut LinearMetaModel) -> Result<(), ModelError> {
+        register_extremum(model, &self._symbol, &self.arguments, false)
+    }
+}
+
+fn fmt_arguments(
+    f: &mut fmt::Formatter<'_>,
+    symbol: &VariableSymbol,
+    function: &str,
+    arguments: &[LinearPolynomial],
+) -> fmt::Result {
+    write!(f, "{} = {}(", symbol, function)?;
+    for (i, argument) in arguments.iter().enumerate() {
+        if i != 0 {
+            write!(f, ", ")?;
+        }
+        write!(f, "{}", argument)?;
+    }
+    write!(f, ")")
+}
+
+impl Display for Max {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_arguments(f, &self._symbol, "max", &self.arguments)
+    }
+}
+
+impl Display for Min {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_arguments(f, &self._symbol, "min", &self.arguments)
+    }
+}
+
+// how far below 1 the gap between the argument and its rounding has to stay,
+// as the strict inequality cannot be stated
+const ROUNDING_EPSILON: f64 = 1e-6;
+
+fn check_epsilon(epsilon: f64) -> Result<(), ModelError> {
+    if 0. < epsilon && epsilon < 1. {
+        Ok(())
+    } else {
+        Err(error(format!("Epsilon {} should be in (0, 1).", epsilon)))
+    }
+}
+
+// floor: y <= x <= y + 1 - epsilon, ceil: y - 1 + epsilon <= x <= y, with an integer y
+fn register_rounding(
+    model: &mut LinearMetaModel,
+    symbol: &VariableSymbol,
+    argument: &LinearPolynomial,
+    epsilon: f64,
+    ceil: bool,
+) -> Result<(), ModelError> {
+    let (lb, ub) = model.bounds_of(argument);
+    let (lb, ub) = if ceil {
+        (lb.ceil(), ub.ceil())
+    } else {
+        (lb.floor(), ub.floor())
+    };
+    let y = add_variable(model, symbol.clone(), VariableCategory::Integer, lb, ub)?;
+    let (gap_lb, gap_ub) = if ceil {
+        (0., 1. - epsilon)
+    } else {
+        (epsilon - 1., 0.)
+    };
+    model.add_constraint(
+        (y - argument.clone())
+            .between(gap_lb, gap_ub)?
+            .with_name(&symbol.name),
+    )?;
+    Ok(())
+}
+
+pub struct Ceil {
+    _symbol: VariableSymbol,
+    pub argument: LinearPolynomial,
+    pub epsilon: f64,
+}
+
+impl Ceil {
+    pub fn new<Expr: Into<LinearPolynomial>>(name: &str, argument: Expr) -> Self {
+        Self {
+            _symbol: new_symbol(name),
+            argument: argument.into(),
+            epsilon: ROUNDING_EPSILON,
+        }
+    }
+
+    pub fn with_epsilon(mut self, epsilon: f64) -> Result<Self, ModelError> {
+        check_epsilon(epsilon)?;
+        self.epsilon = epsilon;
+        Ok(self)
+    }
+}
+
+impl IntermediateSymbol for Ceil {
+    fn symbol(&self) -> &VariableSymbol {
+        &self._symbol
+    }
+
+    fn register(&self, model: &mut LinearMetaModel) -> Result<(), ModelError> {
+        register_rounding(model, &self._symbol, &self.argument, self.epsilon, true)
+    }
+}
+
+impl Display for Ceil {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} = ceil({})", self._symbol, self.argument)
+    }
+}
+
+pub struct Floor {
+    _symbol: VariableSymbol,
+    pub argument: LinearPolynomial,
+    pub epsilon: f64,
+}
+
+impl Floor {
+    pub fn new<Expr: Into<LinearPolynomial>>(name: &str, argument: Expr) -> Self {
+        Self {
+            _symbol: new_symbol(name),
+            argument: argument.into(),
+            epsilon: ROUNDING_EPSILON,
+        }
+    }
+
+    pub fn with_epsilon(mut self, epsilon: f64) -> Result<Self, ModelError> {
+        check_epsilon(epsilon)?;
+        self.epsilon = epsilon;
+        Ok(self)
+    }
+}
+
+impl IntermediateSymbol for Floor {
+    fn symbol(&self) -> &VariableSymbol {
+        &self._symbol
+    }
+
+    fn register(&self, model: &mut LinearMetaModel) -> Result<(), ModelError> {
+        register_rounding(model, &self._symbol, &self.argument, self.epsilon, false)
+    }
+}
+
+impl Display for Floor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} = floor({})", self._symbol, self.argument)
+    }
+}
+
+#[cfg(test)]
+fn feasible(model: &LinearMetaModel, values: &std::collections::HashMap<String, f64>) -> bool {
+    let value_of = |symbol: &VariableSymbol| values.get(&symbol.to_string()).copied();
+    model
+        .constraints()
+        .iter()
+        .all(|constraint| constraint.slack(value_of).unwrap() >= -1e-9)
+}
+
+#[test]
+fn test_abs_and_max() {
+    use super::super::variable::*;
+    use std::collections::HashMap;
+
+    let mut x = IntVar::new_with_name("x");
+    x.range.set_lb(-3).unwrap();
+    x.range.set_ub(5).unwrap();
+    let mut model = LinearMetaModel::new();
+    model.add_var(&x).unwrap();
+
+    let abs = Abs::new("abs_x", &x);
+    assert_eq!(format!("{}", model.add_symbol(&abs).unwrap()), "abs_x");
+    model.add_symbol(&abs).unwrap();
+    assert_eq!(model.variables().len(), 5);
+    assert_eq!(model.constraints().len(), 4);
+    let mut values: HashMap<String, f64> = [
+        ("x", -3.),
+        ("abs_x", 3.),
+        ("abs_x_pos", 0.),
+        ("abs_x_neg", 3.),
+        ("abs_x_sign", 0.),
+    ]
+    .iter()
+    .map(|(name, value)| (name.to_string(), *value))
+    .collect();
+    assert!(feasible(&model, &values));
+    values.insert("abs_x".to_string(), 2.);
+    assert!(!feasible(&model, &values));
+    values.insert("abs_x".to_string(), 3.);
+
+    let max = Max::new(
+        "max",
+        vec![LinearPolynomial::from(&x), abs.expression() - 4.],
+    )
+    .unwrap();
+    model.add_symbol(&max).unwrap();
+    assert_eq!(format!("{}", max), "max = max(x, abs_x - 4)");
+    values.insert("max".to_string(), -1.);
+    values.insert("max_select0".to_string(), 0.);
+    values.insert("max_select1".to_string(), 1.);
+    assert!(feasible(&model, &values));
+    values.insert("max_select0".to_string(), 1.);
+    values.insert("max_select1".to_string(), 0.);
+    assert!(!feasible(&model, &values));
+}
+
+#[test]
+fn test_invalid_symbols() {
+    use super::super::variable::*;
+
+    let x = RealVar::new_with_name("x");
+    let mut model = LinearMetaModel::new();
+    model.add_var(&x).unwrap();
+
+    let abs = Abs::new("abs_x", &x);
+    assert_eq!(
+        model.add_symbol(&abs).unwrap_err().code(),
+        ospf_rust_base::ErrorCode::OREngineModelingException
+    );
+    assert!(Max::new("max", Vec::<LinearPolynomial>::new()).is_err());
+    assert!(Floor::new("floor_x", &x).with_epsilon(1.).is_err());
+    assert!(Ceil::new("ceil_x", &x).with_epsilon(0.5).is_ok());
+}
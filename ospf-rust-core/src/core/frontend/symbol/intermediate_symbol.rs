@@ -0,0 +1,96 @@
+use super::super::expression::*;
+use super::super::model::{LinearMetaModel, ModelError, ModelVariable, VariableEncoding};
+use super::super::variable::item::new_identifier;
+use super::super::variable::VariableCategory;
+use ospf_rust_base::ErrorCode;
+use std::fmt::Display;
+
+// a function of expressions carried by a variable of its own, whose value is pinned to
+// the function by the auxiliary variables and constraints added in register
+pub trait IntermediateSymbol: Display {
+    fn symbol(&self) -> &VariableSymbol;
+
+    fn expression(&self) -> LinearPolynomial {
+        LinearPolynomial::from(LinearMonomial::new(1., self.symbol().clone()))
+    }
+
+    // the variables of the arguments should be in the model already,
+    // as the linearization is derived from their bounds
+    fn register(&self, model: &mut LinearMetaModel) -> Result<(), ModelError>;
+}
+
+// the symbol takes index 0 of a fresh identifier, its auxiliary variables the following ones
+pub(crate) fn new_symbol(name: &str) -> VariableSymbol {
+    VariableSymbol::new(new_identifier(), 0, name)
+}
+
+pub(crate) fn auxiliary_symbol(
+    symbol: &VariableSymbol,
+    index: usize,
+    suffix: &str,
+) -> VariableSymbol {
+    VariableSymbol::new(symbol.identifier, index, &format!("{}_{}", symbol, suffix))
+}
+
+pub(crate) fn error(message: String) -> ModelError {
+    ModelError::new(ErrorCode::OREngineModelingException, message)
+}
+
+pub(crate) fn arguments_of<Expr: Into<LinearPolynomial>, I: IntoIterator<Item = Expr>>(
+    name: &str,
+    arguments: I,
+) -> Result<Vec<LinearPolynomial>, ModelError> {
+    let arguments: Vec<LinearPolynomial> = arguments.into_iter().map(|arg| arg.into()).collect();
+    if arguments.is_empty() {
+        return Err(error(format!("{} should have arguments.", name)));
+    }
+    Ok(arguments)
+}
+
+pub(crate) fn add_variable(
+    model: &mut LinearMetaModel,
+    symbol: VariableSymbol,
+    category: VariableCategory,
+    lb: f64,
+    ub: f64,
+) -> Result<LinearPolynomial, ModelError> {
+    let ret = LinearPolynomial::from(LinearMonomial::new(1., symbol.clone()));
+    model.add_model_variable(ModelVariable {
+        symbol: symbol,
+        category: category,
+        lower_bound: lb,
+        upper_bound: ub,
+        encoding: VariableEncoding::Native,
+    })?;
+    Ok(ret)
+}
+
+pub(crate) fn finite_bounds_of(
+    model: &LinearMetaModel,
+    expression: &LinearPolynomial,
+    symbol: &VariableSymbol,
+) -> Result<(f64, f64), ModelError> {
+    let (lb, ub) = model.bounds_of(expression);
+    if !(lb.is_finite() && ub.is_finite()) {
+        return Err(error(format!(
+            "Argument {} of {} should be bounded to be linearized, not in [{}, {}].",
+            expression, symbol, lb, ub
+        )));
+    }
+    Ok((lb, ub))
+}
+
+pub(crate) fn check_binary(
+    model: &LinearMetaModel,
+    expression: &LinearPolynomial,
+    symbol: &VariableSymbol,
+) -> Result<(), ModelError> {
+    let (lb, ub) = model.bounds_of(expression);
+    if !(0. <= lb && ub <= 1.) {
+        return Err(error(format!(
+            "Argument {} of {} should be binary, not in [{}, {}].",
+            expression, symbol, lb, ub
+        )));
+    }
+    Ok(())
+}
@@ -0,0 +1,195 @@
+use super::super::constraint::LinearComparison;
+use super::super::expression::*;
+use super::super::model::{LinearMetaModel, ModelError};
+use super::super::variable::VariableCategory;
+use super::intermediate_symbol::*;
+use std::fmt;
+use std::fmt::Display;
+
+fn fmt_arguments(
+    f: &mut fmt::Formatter<'_>,
+    symbol: &VariableSymbol,
+    separator: &str,
+    arguments: &[LinearPolynomial],
+) -> fmt::Result {
+    write!(f, "{} = ", symbol)?;
+    for (i, argument) in arguments.iter().enumerate() {
+        if i != 0 {
+            write!(f, " {} ", separator)?;
+        }
+        write!(f, "({})", argument)?;
+    }
+    Ok(())
+}
+
+pub struct And {
+    _symbol: VariableSymbol,
+    pub arguments: Vec<LinearPolynomial>,
+}
+
+impl And {
+    pub fn new<Expr: Into<LinearPolynomial>, I: IntoIterator<Item = Expr>>(
+        name: &str,
+        arguments: I,
+    ) -> Result<Self, ModelError> {
+        Ok(Self {
+            _symbol: new_symbol(name),
+            arguments: arguments_of(name, arguments)?,
+        })
+    }
+}
+
+impl IntermediateSymbol for And {
+    fn symbol(&self) -> &VariableSymbol {
+        &self._symbol
+    }
+
+    // y <= x_i for each i, and y >= sum of x_i - (n - 1)
+    fn register(&self, model: &mut LinearMetaModel) -> Result<(), ModelError> {
+        let symbol = &self._symbol;
+        for argument in self.arguments.iter() {
+            check_binary(model, argument, symbol)?;
+        }
+        let y = add_variable(model, symbol.clone(), VariableCategory::Binary, 0., 1.)?;
+        let mut sum = LinearPolynomial::new();
+        for (i, argument) in self.arguments.iter().enumerate() {
+            model.add_constraint(
+                y.clone()
+                    .leq(argument.clone())
+                    .with_name(&format!("{}_{}", symbol, i)),
+            )?;
+            sum += argument.clone();
+        }
+        let amount = self.arguments.len() as f64;
+        model.add_constraint(
+            (y - sum)
+                .geq(1. - amount)
+                .with_name(&format!("{}_all", symbol)),
+        )?;
+        Ok(())
+    }
+}
+
+impl Display for And {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_arguments(f, &self._symbol, "and", &self.arguments)
+    }
+}
+
+pub struct Or {
+    _symbol: VariableSymbol,
+    pub arguments: Vec<LinearPolynomial>,
+}
+
+impl Or {
+    pub fn new<Expr: Into<LinearPolynomial>, I: IntoIterator<Item = Expr>>(
+        name: &str,
+        arguments: I,
+    ) -> Result<Self, ModelError> {
+        Ok(Self {
+            _symbol: new_symbol(name),
+            arguments: arguments_of(name, arguments)?,
+        })
+    }
+}
+
+impl IntermediateSymbol for Or {
+    fn symbol(&self) -> &VariableSymbol {
+        &self._symbol
+    }
+
+    // y >= x_i for each i, and y <= sum of x_i
+    fn register(&self, model: &mut LinearMetaModel) -> Result<(), ModelError> {
+        let symbol = &self._symbol;
+        for argument in self.arguments.iter() {
+            check_binary(model, argument, symbol)?;
+        }
+        let y = add_variable(model, symbol.clone(), VariableCategory::Binary, 0., 1.)?;
+        let mut sum = LinearPolynomial::new();
+        for (i, argument) in self.arguments.iter().enumerate() {
+            model.add_constraint(
+                y.clone()
+                    .geq(argument.clone())
+                    .with_name(&format!("{}_{}", symbol, i)),
+            )?;
+            sum += argument.clone();
+        }
+        model.add_constraint((y - sum).leq(0.).with_name(&format!("{}_any", symbol)))?;
+        Ok(())
+    }
+}
+
+impl Display for Or {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_arguments(f, &self._symbol, "or", &self.arguments)
+    }
+}
+
+pub struct Not {
+    _symbol: VariableSymbol,
+    pub argument: LinearPolynomial,
+}
+
+impl Not {
+    pub fn new<Expr: Into<LinearPolynomial>>(name: &str, argument: Expr) -> Self {
+        Self {
+            _symbol: new_symbol(name),
+            argument: argument.into(),
+        }
+    }
+}
+
+impl IntermediateSymbol for Not {
+    fn symbol(&self) -> &VariableSymbol {
+        &self._symbol
+    }
+
+    fn register(&self, model: &mut LinearMetaModel) -> Result<(), ModelError> {
+        let symbol = &self._symbol;
+        check_binary(model, &self.argument, symbol)?;
+        let y = add_variable(model, symbol.clone(), VariableCategory::Binary, 0., 1.)?;
+        model.add_constraint(
+            (y + self.argument.clone())
+                .equal(1.)
+                .with_name(&symbol.name),
+        )?;
+        Ok(())
+    }
+}
+
+impl Display for Not {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} = not ({})", self._symbol, self.argument)
+    }
+}
+
+#[test]
+fn test_logic_functions() {
+    use super::super::variable::*;
+
+    let a = BinVar::new_with_name("a");
+    let b = BinVar::new_with_name("b");
+    let mut x = RealVar::new_with_name("x");
+    x.range.set_lb(0.).unwrap();
+    x.range.set_ub(5.).unwrap();
+    let mut model = LinearMetaModel::new();
+    model.add_var(&a).unwrap();
+    model.add_var(&b).unwrap();
+    model.add_var(&x).unwrap();
+
+    let and = And::new("and", [&a, &b]).unwrap();
+    model.add_symbol(&and).unwrap();
+    assert_eq!(model.variables().len(), 4);
+    assert_eq!(model.constraints().len(), 3);
+
+    // nothing of a rejected symbol stays in the model, so it is rejected again
+    let or = Or::new(
+        "or",
+        [LinearPolynomial::from(&a), LinearPolynomial::from(&x)],
+    )
+    .unwrap();
+    assert!(model.add_symbol(&or).is_err());
+    assert!(model.add_symbol(&or).is_err());
+    assert_eq!(model.variables().len(), 4);
+    assert_eq!(model.constraints().len(), 3);
+}
@@ -0,0 +1,11 @@
+pub mod arithmetic_function;
+pub mod intermediate_symbol;
+pub mod logic_function;
+pub mod piecewise_linear_function;
+
+pub use arithmetic_function::{Abs, Ceil, Floor, Max, Min};
+pub use intermediate_symbol::IntermediateSymbol;
+pub use logic_function::{And, Not, Or};
+pub use piecewise_linear_function::{
+    BivariatePiecewiseLinearFunction, UnivariatePiecewiseLinearFunction,
+};
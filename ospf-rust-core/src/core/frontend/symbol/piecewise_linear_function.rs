@@ -0,0 +1,320 @@
+use super::super::constraint::{LinearComparison, SosCategory, SpecialOrderedSet};
+use super::super::expression::*;
+use super::super::model::{LinearMetaModel, ModelError};
+use super::super::variable::VariableCategory;
+use super::intermediate_symbol::*;
+use std::fmt;
+use std::fmt::Display;
+
+fn check_grid(name: &str, grid: &[f64]) -> Result<(), ModelError> {
+    if grid.len() < 2 {
+        return Err(error(format!("{} needs at least 2 breakpoints.", name)));
+    }
+    for k in 1..grid.len() {
+        if !(grid[k - 1] < grid[k]) {
+            return Err(error(format!(
+                "Breakpoints of {} should be increasing, {} is followed by {}.",
+                name,
+                grid[k - 1],
+                grid[k]
+            )));
+        }
+    }
+    Ok(())
+}
+
+fn sos2(name: String, symbols: Vec<VariableSymbol>) -> SpecialOrderedSet {
+    let weights = (1..=symbols.len()).map(|weight| weight as f64).collect();
+    SpecialOrderedSet {
+        category: SosCategory::Sos2,
+        symbols: symbols,
+        weights: weights,
+        name: name,
+    }
+}
+
+// y = f(x) interpolated between the breakpoints (x_k, f(x_k)), x being kept within them
+pub struct UnivariatePiecewiseLinearFunction {
+    _symbol: VariableSymbol,
+    pub argument: LinearPolynomial,
+    pub points: Vec<(f64, f64)>,
+}
+
+impl UnivariatePiecewiseLinearFunction {
+    pub fn new<Expr: Into<LinearPolynomial>>(
+        name: &str,
+        argument: Expr,
+        points: Vec<(f64, f64)>,
+    ) -> Result<Self, ModelError> {
+        let grid: Vec<f64> = points.iter().map(|(x, _)| *x).collect();
+        check_grid(name, &grid)?;
+        Ok(Self {
+            _symbol: new_symbol(name),
+            argument: argument.into(),
+            points: points,
+        })
+    }
+}
+
+impl IntermediateSymbol for UnivariatePiecewiseLinearFunction {
+    fn symbol(&self) -> &VariableSymbol {
+        &self._symbol
+    }
+
+    // x and y are the same convex combination of the breakpoints,
+    // whose weights are a SOS2 so that only one segment is used
+    fn register(&self, model: &mut LinearMetaModel) -> Result<(), ModelError> {
+        let symbol = &self._symbol;
+        let lb = self
+            .points
+            .iter()
+            .map(|(_, y)| *y)
+            .fold(f64::INFINITY, f64::min);
+        let ub = self
+            .points
+            .iter()
+            .map(|(_, y)| *y)
+            .fold(f64::NEG_INFINITY, f64::max);
+        let y = add_variable(model, symbol.clone(), VariableCategory::Continuous, lb, ub)?;
+
+        let mut weights = Vec::new();
+        let mut sum = LinearPolynomial::new();
+        let mut x_sum = LinearPolynomial::new();
+        let mut y_sum = LinearPolynomial::new();
+        for (k, (x_k, y_k)) in self.points.iter().enumerate() {
+            let weight = auxiliary_symbol(symbol, k + 1, &format!("lambda{}", k));
+            let lambda = add_variable(model, weight.clone(), VariableCategory::Continuous, 0., 1.)?;
+            weights.push(weight);
+            sum += lambda.clone();
+            x_sum += lambda.clone() * *x_k;
+            y_sum += lambda * *y_k;
+        }
+        model.add_constraint(sum.equal(1.).with_name(&format!("{}_convex", symbol)))?;
+        model.add_constraint(
+            x_sum
+                .equal(self.argument.clone())
+                .with_name(&format!("{}_argument", symbol)),
+        )?;
+        model.add_constraint(y.equal(y_sum).with_name(&format!("{}_value", symbol)))?;
+        model.add_special_ordered_set(sos2(format!("{}_segment", symbol), weights))?;
+        Ok(())
+    }
+}
+
+impl Display for UnivariatePiecewiseLinearFunction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} = pwl({}; ", self._symbol, self.argument)?;
+        for (k, (x, y)) in self.points.iter().enumerate() {
+            if k != 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "({}, {})", x, y)?;
+        }
+        write!(f, ")")
+    }
+}
+
+// y = f(x1, x2) on the grid x1_i * x2_j, each cell being split into two triangles by
+// the diagonal from (i, j) to (i + 1, j + 1)
+pub struct BivariatePiecewiseLinearFunction {
+    _symbol: VariableSymbol,
+    pub arguments: (LinearPolynomial, LinearPolynomial),
+    pub grid1: Vec<f64>,
+    pub grid2: Vec<f64>,
+    // values[i][j] = f(grid1[i], grid2[j])
+    pub values: Vec<Vec<f64>>,
+}
+
+impl BivariatePiecewiseLinearFunction {
+    pub fn new<Expr1: Into<LinearPolynomial>, Expr2: Into<LinearPolynomial>>(
+        name: &str,
+        arguments: (Expr1, Expr2),
+        grid1: Vec<f64>,
+        grid2: Vec<f64>,
+        values: Vec<Vec<f64>>,
+    ) -> Result<Self, ModelError> {
+        check_grid(name, &grid1)?;
+        check_grid(name, &grid2)?;
+        if !(values.len() == grid1.len() && values.iter().all(|row| row.len() == grid2.len())) {
+            return Err(error(format!(
+                "Values of {} should be a {} * {} grid.",
+                name,
+                grid1.len(),
+                grid2.len()
+            )));
+        }
+        Ok(Self {
+            _symbol: new_symbol(name),
+            arguments: (arguments.0.into(), arguments.1.into()),
+            grid1: grid1,
+            grid2: grid2,
+            values: values,
+        })
+    }
+}
+
+impl IntermediateSymbol for BivariatePiecewiseLinearFunction {
+    fn symbol(&self) -> &VariableSymbol {
+        &self._symbol
+    }
+
+    // a convex combination of the grid points, whose sums by row, by column and by
+    // diagonal i - j are SOS2s: the first two keep it within a cell, and the last one
+    // forbids the corners (i + 1, j) and (i, j + 1) together, leaving a triangle
+    fn register(&self, model: &mut LinearMetaModel) -> Result<(), ModelError> {
+        let symbol = &self._symbol;
+        let (rows, columns) = (self.grid1.len(), self.grid2.len());
+        let lb = self
+            .values
+            .iter()
+            .flatten()
+            .copied()
+            .fold(f64::INFINITY, f64::min);
+        let ub = self
+            .values
+            .iter()
+            .flatten()
+            .copied()
+            .fold(f64::NEG_INFINITY, f64::max);
+        let y = add_variable(model, symbol.clone(), VariableCategory::Continuous, lb, ub)?;
+
+        let mut index = 0;
+        let mut next_symbol = |suffix: String| {
+            index += 1;
+            auxiliary_symbol(symbol, index, &suffix)
+        };
+        let mut sum = LinearPolynomial::new();
+        let mut x1_sum = LinearPolynomial::new();
+        let mut x2_sum = LinearPolynomial::new();
+        let mut y_sum = LinearPolynomial::new();
+        let mut row_sums = vec![LinearPolynomial::new(); rows];
+        let mut column_sums = vec![LinearPolynomial::new(); columns];
+        let mut diagonal_sums = vec![LinearPolynomial::new(); rows + columns - 1];
+        for i in 0..rows {
+            for j in 0..columns {
+                let lambda = add_variable(
+                    model,
+                    next_symbol(format!("lambda{}_{}", i, j)),
+                    VariableCategory::Continuous,
+                    0.,
+                    1.,
+                )?;
+                sum += lambda.clone();
+                x1_sum += lambda.clone() * self.grid1[i];
+                x2_sum += lambda.clone() * self.grid2[j];
+                y_sum += lambda.clone() * self.values[i][j];
+                row_sums[i] += lambda.clone();
+                column_sums[j] += lambda.clone();
+                diagonal_sums[i + columns - 1 - j] += lambda;
+            }
+        }
+        model.add_constraint(sum.equal(1.).with_name(&format!("{}_convex", symbol)))?;
+        model.add_constraint(
+            x1_sum
+                .equal(self.arguments.0.clone())
+                .with_name(&format!("{}_argument1", symbol)),
+        )?;
+        model.add_constraint(
+            x2_sum
+                .equal(self.arguments.1.clone())
+                .with_name(&format!("{}_argument2", symbol)),
+        )?;
+        model.add_constraint(y.equal(y_sum).with_name(&format!("{}_value", symbol)))?;
+
+        for (direction, sums) in [
+            ("row", row_sums),
+            ("column", column_sums),
+            ("diagonal", diagonal_sums),
+        ] {
+            let mut members = Vec::new();
+            for (k, lambdas) in sums.into_iter().enumerate() {
+                let member = next_symbol(format!("{}{}", direction, k));
+                let total =
+                    add_variable(model, member.clone(), VariableCategory::Continuous, 0., 1.)?;
+                model.add_constraint(
+                    (total - lambdas)
+                        .equal(0.)
+                        .with_name(&format!("{}_{}{}", symbol, direction, k)),
+                )?;
+                members.push(member);
+            }
+            model.add_special_ordered_set(sos2(format!("{}_{}", symbol, direction), members))?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for BivariatePiecewiseLinearFunction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} = pwl({}, {}; {} * {} grid)",
+            self._symbol,
+            self.arguments.0,
+            self.arguments.1,
+            self.grid1.len(),
+            self.grid2.len()
+        )
+    }
+}
+
+#[test]
+fn test_piecewise_linear_function() {
+    use super::super::variable::*;
+
+    let x1 = RealVar::new_with_name("x1");
+    let x2 = RealVar::new_with_name("x2");
+    let mut model = LinearMetaModel::new();
+    model.add_var(&x1).unwrap();
+    model.add_var(&x2).unwrap();
+
+    let f = UnivariatePiecewiseLinearFunction::new("f", &x1, vec![(0., 0.), (1., 2.), (3., 3.)])
+        .unwrap();
+    model.add_symbol(&f).unwrap();
+    assert_eq!(format!("{}", f), "f = pwl(x1; (0, 0), (1, 2), (3, 3))");
+    assert_eq!(model.variables().len(), 6);
+    assert_eq!(model.special_ordered_sets().len(), 1);
+    assert_eq!(
+        (
+            model.variables()[2].lower_bound,
+            model.variables()[2].upper_bound
+        ),
+        (0., 3.)
+    );
+
+    let g = BivariatePiecewiseLinearFunction::new(
+        "g",
+        (&x1, &x2),
+        vec![0., 1., 2.],
+        vec![0., 1.],
+        vec![vec![0., 1.], vec![1., 2.], vec![2., 3.]],
+    )
+    .unwrap();
+    model.add_symbol(&g).unwrap();
+    // 6 weights, 3 row sums, 2 column sums and 4 diagonal sums
+    assert_eq!(model.variables().len(), 6 + 1 + 6 + 3 + 2 + 4);
+    assert_eq!(model.constraints().len(), 3 + 4 + 3 + 2 + 4);
+    let sets = model.special_ordered_sets();
+    assert_eq!(sets.len(), 4);
+    assert_eq!(
+        sets.iter().map(|set| set.symbols.len()).collect::<Vec<_>>(),
+        vec![3, 3, 2, 4]
+    );
+}
+
+#[test]
+fn test_invalid_piecewise_linear_function() {
+    use super::super::variable::*;
+
+    let x = RealVar::new_with_name("x");
+    assert!(UnivariatePiecewiseLinearFunction::new("f", &x, vec![(0., 0.)]).is_err());
+    assert!(UnivariatePiecewiseLinearFunction::new("f", &x, vec![(1., 0.), (0., 1.)]).is_err());
+    assert!(BivariatePiecewiseLinearFunction::new(
+        "g",
+        (&x, &x),
+        vec![0., 1.],
+        vec![0., 1.],
+        vec![vec![0., 1.]],
+    )
+    .is_err());
+}
@@ -1,18 +1,16 @@
 use super::range::VariableRange;
 use super::variable_type::VariableType;
-use crate::data_structure::MultiArray;
+use ospf_rust_multiarray::{DummyIndex, DynShape, MultiArray, Shape};
 use std::fmt;
 use std::fmt::Display;
 use std::hash::*;
-use std::rc::*;
+use std::ops::Index;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::*;
 
-fn new_identifier() -> u64 {
-    static mut NEXT: u64 = 0;
-    unsafe {
-        let ret = NEXT;
-        NEXT += 1;
-        ret
-    }
+pub(crate) fn new_identifier() -> u64 {
+    static NEXT: AtomicU64 = AtomicU64::new(0);
+    NEXT.fetch_add(1, Ordering::Relaxed)
 }
 
 pub trait VariableItem: Display + Hash {
@@ -22,6 +20,8 @@ pub trait VariableItem: Display + Hash {
         Self::Type::new()
     }
 
+    fn name(&self) -> &str;
+    fn range(&self) -> &VariableRange<Self::Type>;
     fn dimension(&self) -> usize;
     fn identifier(&self) -> u64;
     fn index(&self) -> usize;
@@ -86,6 +86,14 @@ impl<Type: VariableType> Hash for IndependentVariableItem<Type> {
 impl<Type: VariableType> VariableItem for IndependentVariableItem<Type> {
     type Type = Type;
 
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn range(&self) -> &VariableRange<Type> {
+        &self.range
+    }
+
     fn dimension(&self) -> usize {
         0
     }
@@ -104,44 +112,59 @@ impl<Type: VariableType> VariableItem for IndependentVariableItem<Type> {
     }
 }
 
-#[derive(Clone)]
+// an item knows what it needs of its combination, so that a clone may outlive it
 pub struct CombinedVariableItem<Type: VariableType, const D: usize> {
     pub name: String,
     pub range: VariableRange<Type>,
-    _parent: Weak<VariableItemCombinationImpl<Type, D>>,
+    _identifier: u64,
+    _shape: Arc<Vec<usize>>,
     _index: usize,
-    _vector_view: Option<Vec<usize>>,
+    _vector_view: Vec<usize>,
 }
 
 pub struct VariableItemCombinationImpl<Type: VariableType, const D: usize> {
     identifier: u64,
     pub name: String,
-    _items: MultiArray<CombinedVariableItem<Type, D>, D>,
+    _items: MultiArray<CombinedVariableItem<Type, D>, DynShape>,
 }
 
 pub struct VariableItemCombination<Type: VariableType, const D: usize> {
-    _impl: Rc<VariableItemCombinationImpl<Type, D>>,
+    _impl: Arc<VariableItemCombinationImpl<Type, D>>,
 }
 
 impl<Type: VariableType, const D: usize> CombinedVariableItem<Type, D> {
-    fn parent(&self) -> Rc<VariableItemCombinationImpl<Type, D>> {
-        match self._parent.upgrade() {
-            Option::Some(rc) => rc,
-            Option::None => panic!(""),
+    fn new(
+        identifier: u64,
+        shape: Arc<Vec<usize>>,
+        index: usize,
+        vector: Vec<usize>,
+        name: String,
+    ) -> Self {
+        Self {
+            name: name,
+            range: VariableRange::<Type>::new(),
+            _identifier: identifier,
+            _shape: shape,
+            _index: index,
+            _vector_view: vector,
         }
     }
 
-    fn get_vector(&self) -> Vec<usize> {
-        let parent = self.parent();
-        let shape = parent._items.shape();
-        let mut s = Vec::<usize>::new();
-        let mut index = self._index;
-        s.resize(shape.len(), 0);
-        for i in 0..shape.len() {
-            s[i] = index / shape[i];
-            index %= shape[i];
+    pub fn shape(&self) -> &[usize] {
+        &self._shape
+    }
+}
+
+impl<Type: VariableType, const D: usize> Clone for CombinedVariableItem<Type, D> {
+    fn clone(&self) -> Self {
+        Self {
+            name: self.name.clone(),
+            range: self.range.clone(),
+            _identifier: self._identifier,
+            _shape: self._shape.clone(),
+            _index: self._index,
+            _vector_view: self._vector_view.clone(),
         }
-        s
     }
 }
 
@@ -160,12 +183,20 @@ impl<Type: VariableType, const D: usize> Hash for CombinedVariableItem<Type, D>
 impl<Type: VariableType, const D: usize> VariableItem for CombinedVariableItem<Type, D> {
     type Type = Type;
 
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn range(&self) -> &VariableRange<Type> {
+        &self.range
+    }
+
     fn dimension(&self) -> usize {
-        self.parent().dimension()
+        self._shape.len()
     }
 
     fn identifier(&self) -> u64 {
-        self.parent().identifier
+        self._identifier
     }
 
     fn index(&self) -> usize {
@@ -173,26 +204,39 @@ impl<Type: VariableType, const D: usize> VariableItem for CombinedVariableItem<T
     }
 
     fn vector_view(&self) -> &Vec<usize> {
-        if self._vector_view.is_none() {
-            unsafe { &mut *(self as *const Self as *mut Self) }._vector_view =
-                Option::Some(self.get_vector());
-        }
-        self._vector_view.as_ref().unwrap()
+        &self._vector_view
+    }
+}
+
+// items of a named combination are named by their vectors, e.g. x_1_3
+fn item_name(name: &str, vector: &[usize]) -> String {
+    if name.is_empty() {
+        String::new()
+    } else {
+        vector.iter().fold(name.to_string(), |name, index| {
+            format!("{}_{}", name, index)
+        })
     }
 }
 
 impl<Type: VariableType, const D: usize> VariableItemCombinationImpl<Type, D> {
-    fn new(name: &str) -> Self {
-        Self {
-            identifier: new_identifier(),
+    fn new(name: &str, shape: DynShape) -> Arc<Self> {
+        let identifier = new_identifier();
+        let dimensions = Arc::new(shape.shape().to_vec());
+        Arc::new(Self {
+            identifier: identifier,
             name: name.to_string(),
-            _items: MultiArray::new(),
-        }
+            _items: MultiArray::new_by(shape.clone(), |index| {
+                let vector = shape.vector(index);
+                let name = item_name(name, &vector);
+                CombinedVariableItem::new(identifier, dimensions.clone(), index, vector, name)
+            }),
+        })
     }
 
     fn dimension(&self) -> usize {
         match D {
-            0 => self._items.dimension(),
+            0 => self._items.shape().dimension(),
             _ => D,
         }
     }
@@ -200,14 +244,234 @@ impl<Type: VariableType, const D: usize> VariableItemCombinationImpl<Type, D> {
 
 impl<Type: VariableType, const D: usize> VariableItemCombination<Type, D> {
     pub fn new() -> Self {
+        Self::new_with_name("")
+    }
+
+    pub fn new_with_name(_name: &str) -> Self {
+        Self::new_with_shape(_name, &vec![0; D.max(1)])
+    }
+
+    // D = 0 stands for a combination whose dimension is only known at runtime
+    pub fn new_with_shape(_name: &str, shape: &[usize]) -> Self {
+        assert!(
+            D == 0 || shape.len() == D,
+            "shape {:?} of {} should have {} dimensions",
+            shape,
+            _name,
+            D
+        );
         Self {
-            _impl: Rc::new(VariableItemCombinationImpl::<Type, D>::new("")),
+            _impl: VariableItemCombinationImpl::<Type, D>::new(
+                _name,
+                DynShape::new(shape.to_vec()),
+            ),
         }
     }
 
-    pub fn new_with_name(_name: &str) -> Self {
+    pub fn name(&self) -> &str {
+        &self._impl.name
+    }
+
+    pub fn identifier(&self) -> u64 {
+        self._impl.identifier
+    }
+
+    pub fn dimension(&self) -> usize {
+        self._impl.dimension()
+    }
+
+    pub fn shape(&self) -> &[usize] {
+        self._impl._items.shape().shape()
+    }
+
+    pub fn len(&self) -> usize {
+        self._impl._items.len()
+    }
+
+    pub fn get(&self, vector: &[usize]) -> Option<&CombinedVariableItem<Type, D>> {
+        let shape = self._impl._items.shape();
+        if vector.len() != shape.dimension() {
+            return Option::None;
+        }
+        match shape.index(&vector.to_vec()) {
+            Ok(index) => Option::Some(&self._impl._items[index]),
+            Err(_) => Option::None,
+        }
+    }
+
+    // bounds of an item can only be tightened while no clone of the combination shares it
+    pub fn get_mut(&mut self, vector: &[usize]) -> Option<&mut CombinedVariableItem<Type, D>> {
+        let combination = Arc::get_mut(&mut self._impl)?;
+        let shape = combination._items.shape();
+        if vector.len() != shape.dimension() {
+            return Option::None;
+        }
+        match shape.index(&vector.to_vec()) {
+            Ok(index) => Option::Some(&mut combination._items[index]),
+            Err(_) => Option::None,
+        }
+    }
+
+    // items picked by one selector per dimension, e.g. [1.into(), (..).into()] for a row;
+    // none if the selector does not fit the shape, as with get
+    pub fn select<V: Into<Vec<DummyIndex>>>(
+        &self,
+        selector: V,
+    ) -> Option<Vec<&CombinedVariableItem<Type, D>>> {
+        let selector = selector.into();
+        if selector.len() != self.dimension() {
+            return Option::None;
+        }
+        if self.len() == 0 {
+            return Option::Some(Vec::new());
+        }
+        match self._impl._items.get(selector) {
+            Ok(items) => Option::Some(items.into_iter().filter_map(|item| item.as_ref()).collect()),
+            Err(_) => Option::None,
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &CombinedVariableItem<Type, D>> {
+        self._impl._items.iter()
+    }
+}
+
+// clones share the same items
+impl<Type: VariableType, const D: usize> Clone for VariableItemCombination<Type, D> {
+    fn clone(&self) -> Self {
         Self {
-            _impl: Rc::new(VariableItemCombinationImpl::<Type, D>::new(_name)),
+            _impl: self._impl.clone(),
+        }
+    }
+}
+
+impl<Type: VariableType, const D: usize> Index<usize> for VariableItemCombination<Type, D> {
+    type Output = CombinedVariableItem<Type, D>;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self._impl._items[index]
+    }
+}
+
+impl<Type: VariableType, const D: usize> Index<&[usize]> for VariableItemCombination<Type, D> {
+    type Output = CombinedVariableItem<Type, D>;
+
+    fn index(&self, vector: &[usize]) -> &Self::Output {
+        match self.get(vector) {
+            Option::Some(item) => item,
+            Option::None => panic!(
+                "vector {:?} is out of the shape {:?} of {}",
+                vector,
+                self.shape(),
+                self.name()
+            ),
         }
     }
 }
+
+impl<Type: VariableType, const D: usize> Index<&[usize; D]> for VariableItemCombination<Type, D> {
+    type Output = CombinedVariableItem<Type, D>;
+
+    fn index(&self, vector: &[usize; D]) -> &Self::Output {
+        &self[&vector[..]]
+    }
+}
+
+impl<'a, Type: VariableType, const D: usize> IntoIterator for &'a VariableItemCombination<Type, D> {
+    type Item = &'a CombinedVariableItem<Type, D>;
+    type IntoIter = Box<dyn Iterator<Item = Self::Item> + 'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        Box::new(self.iter())
+    }
+}
+
+#[test]
+fn test_variable_item_combination() {
+    use super::variable_type::*;
+
+    let x = VariableItemCombination::<Binary, 2>::new_with_shape("x", &[2, 3]);
+    assert_eq!((x.dimension(), x.len()), (2, 6));
+    assert_eq!(x[&[1, 2]].name(), "x_1_2");
+    assert_eq!(x[&[1, 2]].index(), 5);
+    assert!(x.get(&[2, 0]).is_none());
+
+    let row: Vec<&str> = x
+        .select([DummyIndex::from(1usize), DummyIndex::from(..)])
+        .unwrap()
+        .into_iter()
+        .map(|item| item.name())
+        .collect();
+    assert_eq!(row, vec!["x_1_0", "x_1_1", "x_1_2"]);
+    assert_eq!(
+        x.select([DummyIndex::from(..), DummyIndex::from(0usize)])
+            .unwrap()
+            .len(),
+        2
+    );
+    assert!(x.select([DummyIndex::from(1usize)]).is_none());
+    assert!(x.iter().all(|item| item.identifier() == x.identifier()));
+}
+
+#[test]
+fn test_variable_item_vector_view() {
+    use super::variable_type::*;
+
+    let x = VariableItemCombination::<Integer, 3>::new_with_shape("x", &[2, 3, 4]);
+    assert_eq!(x[13].vector_view(), &vec![1, 0, 1]);
+    assert_eq!(x[23].vector_view(), &vec![1, 2, 3]);
+    assert!(x
+        .iter()
+        .all(|item| x[&item.vector_view()[..]].index() == item.index()));
+}
+
+#[test]
+fn test_variable_item_get_mut() {
+    use super::variable_type::*;
+
+    let mut x = VariableItemCombination::<Integer, 2>::new_with_shape("x", &[2, 3]);
+    x.get_mut(&[1, 2]).unwrap().range.set_ub(4).unwrap();
+    assert_eq!(x[&[1, 2]].range.upper_bound(), 4.);
+    assert!(x.get_mut(&[2, 0]).is_none());
+
+    let y = x.clone();
+    assert!(x.get_mut(&[1, 2]).is_none());
+    drop(y);
+    assert!(x.get_mut(&[1, 2]).is_some());
+}
+
+#[test]
+fn test_variable_item_outlives_combination() {
+    use super::variable_type::*;
+
+    let x = VariableItemCombination::<Integer, 2>::new_with_shape("x", &[2, 3]);
+    let identifier = x.identifier();
+    let item = x[&[1, 2]].clone();
+    drop(x);
+    assert_eq!(item.identifier(), identifier);
+    assert_eq!(item.dimension(), 2);
+    assert_eq!(item.shape(), &[2, 3]);
+}
+
+#[test]
+fn test_variable_items_across_threads() {
+    use super::variable_type::*;
+
+    let handles: Vec<_> = (0..4)
+        .map(|_| {
+            std::thread::spawn(|| VariableItemCombination::<Integer, 1>::new_with_shape("y", &[8]))
+        })
+        .collect();
+    let combinations: Vec<_> = handles
+        .into_iter()
+        .map(|handle| handle.join().unwrap())
+        .collect();
+    let mut identifiers: Vec<_> = combinations.iter().map(|y| y.identifier()).collect();
+    identifiers.sort();
+    identifiers.dedup();
+    assert_eq!(identifiers.len(), 4);
+    assert_eq!(
+        combinations[0][&[7]].identifier(),
+        combinations[0].identifier()
+    );
+}
@@ -28,3 +28,5 @@ variable_type_exporter_template!(Int, Integer);
 variable_type_exporter_template!(UInt, UInteger);
 variable_type_exporter_template!(Real, Continuous);
 variable_type_exporter_template!(UReal, UContinuous);
+variable_type_exporter_template!(SemiReal, SemiContinuous);
+variable_type_exporter_template!(SemiInt, SemiInteger);
@@ -1,27 +1,137 @@
-use super::variable_type::VariableType;
-use crate::math::value_range::*;
-use std::convert::Into;
+use super::super::model::ModelError;
+use super::variable_type::{BoundValue, VariableType};
+use ospf_rust_base::ErrorCode;
+use ospf_rust_math::algebra::concept::Arithmetic;
+use ospf_rust_math::algebra::value_range::*;
 use std::fmt;
 use std::fmt::Display;
 
-#[derive(Clone)]
+fn bound_value<T: BoundValue + Arithmetic>(bound: &Option<Bound<T>>) -> f64 {
+    match bound {
+        Option::Some(bound) => match &bound.value {
+            ValueWrapper::Value(value) => value.to_bound(),
+            ValueWrapper::Inf => f64::INFINITY,
+            ValueWrapper::NegInf => f64::NEG_INFINITY,
+        },
+        Option::None => f64::NAN,
+    }
+}
+
 pub struct VariableRange<Type: VariableType> {
-    _range: Type::ValueRangeType,
+    _range: ValueRange<Type::ValueType>,
 }
 
 impl<Type: VariableType> VariableRange<Type> {
     pub fn new() -> Self {
         Self {
-            _range: Type::ValueRangeType::new_range(
-                &Type::default_minimum().into(),
-                &Type::default_maximum().into(),
+            _range: ValueRange::new_with(
+                Type::default_minimum(),
+                Type::default_maximum(),
+                Interval::Closed,
+                Interval::Closed,
             ),
         }
     }
+
+    pub fn range(&self) -> &ValueRange<Type::ValueType> {
+        &self._range
+    }
+
+    pub fn lower_bound(&self) -> f64 {
+        bound_value(&self._range.lb)
+    }
+
+    pub fn upper_bound(&self) -> f64 {
+        bound_value(&self._range.ub)
+    }
+
+    pub fn set_lb(&mut self, value: Type::ValueType) -> Result<(), ModelError> {
+        Self::check(&value)?;
+        self._range
+            .set_lb(ValueWrapper::Value(value), Interval::Closed);
+        Ok(())
+    }
+
+    pub fn set_ub(&mut self, value: Type::ValueType) -> Result<(), ModelError> {
+        Self::check(&value)?;
+        self._range
+            .set_ub(ValueWrapper::Value(value), Interval::Closed);
+        Ok(())
+    }
+
+    pub fn fix(&mut self, value: Type::ValueType) -> Result<(), ModelError> {
+        Self::check(&value)?;
+        self.set_lb(value)?;
+        self.set_ub(value)
+    }
+
+    pub fn intersect_with(&mut self, rhs: &Self) {
+        self._range = self._range.intersect(&rhs._range);
+    }
+
+    pub fn empty(&self) -> bool {
+        self._range.empty()
+    }
+
+    fn check(value: &Type::ValueType) -> Result<(), ModelError> {
+        if Type::default_minimum() <= *value && *value <= Type::default_maximum() {
+            Ok(())
+        } else {
+            Err(ModelError::new(
+                ErrorCode::OREngineModelingException,
+                format!(
+                    "Bound {} is out of the domain [{}, {}] of {}.",
+                    value,
+                    Type::default_minimum(),
+                    Type::default_maximum(),
+                    Type::name()
+                ),
+            ))
+        }
+    }
+}
+
+impl<Type: VariableType> Clone for VariableRange<Type> {
+    fn clone(&self) -> Self {
+        Self {
+            _range: self._range.clone(),
+        }
+    }
 }
 
 impl<Type: VariableType> Display for VariableRange<Type> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self._range)
+        if self.empty() {
+            write!(f, "empty")
+        } else {
+            write!(f, "[{}, {}]", self.lower_bound(), self.upper_bound())
+        }
     }
 }
+
+#[test]
+fn test_variable_range_tighten() {
+    use super::variable_type::*;
+
+    let mut range = VariableRange::<Integer>::new();
+    range.set_lb(2).unwrap();
+    range.set_ub(5).unwrap();
+    assert_eq!((range.lower_bound(), range.upper_bound()), (2., 5.));
+
+    let mut rhs = VariableRange::<Integer>::new();
+    rhs.fix(7).unwrap();
+    range.intersect_with(&rhs);
+    assert!(range.empty());
+}
+
+#[test]
+fn test_variable_range_out_of_domain() {
+    use super::variable_type::*;
+
+    let mut range = VariableRange::<Binary>::new();
+    assert_eq!(
+        range.set_ub(3).err().unwrap().code(),
+        ErrorCode::OREngineModelingException
+    );
+    assert_eq!(range.upper_bound(), 1.);
+}
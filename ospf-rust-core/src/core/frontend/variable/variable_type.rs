@@ -1,6 +1,5 @@
-use crate::math::concept::*;
-use crate::math::value_range::*;
-use std::convert::Into;
+use ospf_rust_math::algebra::concept::Arithmetic;
+use std::fmt;
 use std::{f64, i128, i8, u128, u8};
 
 pub struct Binary;
@@ -11,23 +10,87 @@ pub struct Integer;
 pub struct UInteger;
 pub struct Continuous;
 pub struct UContinuous;
+pub struct SemiContinuous;
+pub struct SemiInteger;
+
+// the column kind used by the backends, the bounds come from the range of the variable,
+// e.g. Ternary is an integer column in [0, 2] and Percentage a continuous one in [0, 1]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum VariableCategory {
+    Binary,
+    Integer,
+    Continuous,
+    // 0 or within the range of the variable
+    SemiContinuous,
+    SemiInteger,
+}
+
+impl VariableCategory {
+    pub fn is_semi(&self) -> bool {
+        match self {
+            VariableCategory::SemiContinuous | VariableCategory::SemiInteger => true,
+            _ => false,
+        }
+    }
+}
+
+pub trait BoundValue {
+    fn to_bound(&self) -> f64;
+}
+
+impl BoundValue for u8 {
+    fn to_bound(&self) -> f64 {
+        *self as f64
+    }
+}
+
+impl BoundValue for i8 {
+    fn to_bound(&self) -> f64 {
+        *self as f64
+    }
+}
+
+impl BoundValue for i128 {
+    fn to_bound(&self) -> f64 {
+        match *self {
+            i128::MIN => f64::NEG_INFINITY,
+            i128::MAX => f64::INFINITY,
+            value => value as f64,
+        }
+    }
+}
+
+impl BoundValue for u128 {
+    fn to_bound(&self) -> f64 {
+        match *self {
+            u128::MAX => f64::INFINITY,
+            value => value as f64,
+        }
+    }
+}
+
+impl BoundValue for f64 {
+    fn to_bound(&self) -> f64 {
+        *self
+    }
+}
 
 pub trait VariableType {
-    type ValueRangeType: ValueRange;
-    type ValueType: Arithmetic + Constant + Into<<Self::ValueRangeType as ValueRange>::ValueType>;
+    type ValueType: Arithmetic + BoundValue + Copy + fmt::Display;
 
     fn new() -> Self;
 
     fn default_minimum() -> Self::ValueType;
     fn default_maximum() -> Self::ValueType;
 
+    fn category() -> VariableCategory;
+
     fn name() -> &'static str;
     fn short_name() -> &'static str;
 }
 
 impl VariableType for Binary {
     type ValueType = u8;
-    type ValueRangeType = ValueRangeType<u8>;
 
     fn new() -> Self {
         Self {}
@@ -40,6 +103,10 @@ impl VariableType for Binary {
         1
     }
 
+    fn category() -> VariableCategory {
+        VariableCategory::Binary
+    }
+
     fn name() -> &'static str {
         "Binary"
     }
@@ -50,7 +117,6 @@ impl VariableType for Binary {
 
 impl VariableType for Ternary {
     type ValueType = u8;
-    type ValueRangeType = ValueRangeType<u8>;
 
     fn new() -> Self {
         Self {}
@@ -63,6 +129,10 @@ impl VariableType for Ternary {
         2
     }
 
+    fn category() -> VariableCategory {
+        VariableCategory::Integer
+    }
+
     fn name() -> &'static str {
         "Ternary"
     }
@@ -73,7 +143,6 @@ impl VariableType for Ternary {
 
 impl VariableType for BalancedTernary {
     type ValueType = i8;
-    type ValueRangeType = ValueRangeType<i8>;
 
     fn new() -> Self {
         Self {}
@@ -86,6 +155,10 @@ impl VariableType for BalancedTernary {
         1
     }
 
+    fn category() -> VariableCategory {
+        VariableCategory::Integer
+    }
+
     fn name() -> &'static str {
         "BalancedTernary"
     }
@@ -96,7 +169,6 @@ impl VariableType for BalancedTernary {
 
 impl VariableType for Percentage {
     type ValueType = f64;
-    type ValueRangeType = ValueRangeType<f64>;
 
     fn new() -> Self {
         Self {}
@@ -109,6 +181,10 @@ impl VariableType for Percentage {
         1.
     }
 
+    fn category() -> VariableCategory {
+        VariableCategory::Continuous
+    }
+
     fn name() -> &'static str {
         "Percentage"
     }
@@ -119,7 +195,6 @@ impl VariableType for Percentage {
 
 impl VariableType for Integer {
     type ValueType = i128;
-    type ValueRangeType = ValueRangeType<i128>;
 
     fn new() -> Self {
         Self {}
@@ -132,6 +207,10 @@ impl VariableType for Integer {
         i128::MAX
     }
 
+    fn category() -> VariableCategory {
+        VariableCategory::Integer
+    }
+
     fn name() -> &'static str {
         "Integer"
     }
@@ -142,7 +221,6 @@ impl VariableType for Integer {
 
 impl VariableType for UInteger {
     type ValueType = u128;
-    type ValueRangeType = ValueRangeType<u128>;
 
     fn new() -> Self {
         Self {}
@@ -155,6 +233,10 @@ impl VariableType for UInteger {
         u128::MAX
     }
 
+    fn category() -> VariableCategory {
+        VariableCategory::Integer
+    }
+
     fn name() -> &'static str {
         "UInteger"
     }
@@ -165,7 +247,6 @@ impl VariableType for UInteger {
 
 impl VariableType for Continuous {
     type ValueType = f64;
-    type ValueRangeType = ValueRangeType<f64>;
 
     fn new() -> Self {
         Self {}
@@ -178,6 +259,10 @@ impl VariableType for Continuous {
         f64::INFINITY
     }
 
+    fn category() -> VariableCategory {
+        VariableCategory::Continuous
+    }
+
     fn name() -> &'static str {
         "Continuous"
     }
@@ -188,7 +273,6 @@ impl VariableType for Continuous {
 
 impl VariableType for UContinuous {
     type ValueType = f64;
-    type ValueRangeType = ValueRangeType<f64>;
 
     fn new() -> Self {
         Self {}
@@ -201,6 +285,10 @@ impl VariableType for UContinuous {
         f64::INFINITY
     }
 
+    fn category() -> VariableCategory {
+        VariableCategory::Continuous
+    }
+
     fn name() -> &'static str {
         "UContinuous"
     }
@@ -208,3 +296,55 @@ impl VariableType for UContinuous {
         "UReal"
     }
 }
+
+impl VariableType for SemiContinuous {
+    type ValueType = f64;
+
+    fn new() -> Self {
+        Self {}
+    }
+
+    fn default_minimum() -> f64 {
+        0.
+    }
+    fn default_maximum() -> f64 {
+        f64::INFINITY
+    }
+
+    fn category() -> VariableCategory {
+        VariableCategory::SemiContinuous
+    }
+
+    fn name() -> &'static str {
+        "SemiContinuous"
+    }
+    fn short_name() -> &'static str {
+        "SemiReal"
+    }
+}
+
+impl VariableType for SemiInteger {
+    type ValueType = u128;
+
+    fn new() -> Self {
+        Self {}
+    }
+
+    fn default_minimum() -> u128 {
+        u128::MIN
+    }
+    fn default_maximum() -> u128 {
+        u128::MAX
+    }
+
+    fn category() -> VariableCategory {
+        VariableCategory::SemiInteger
+    }
+
+    fn name() -> &'static str {
+        "SemiInteger"
+    }
+    fn short_name() -> &'static str {
+        "SemiInt"
+    }
+}
@@ -1,2 +1,3 @@
+pub mod error;
 pub mod frontend;
 pub mod backend;
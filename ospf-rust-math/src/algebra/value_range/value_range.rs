@@ -7,6 +7,27 @@ pub(self) fn empty<T: Arithmetic>(lb: &T, ub: &T, lb_interval: Interval, ub_inte
     !(lb_interval.lb_op())(lb, ub) || !(ub_interval.ub_op())(lb, ub)
 }
 
+fn tighter<T: Arithmetic>(
+    lhs: &Option<Bound<T>>,
+    rhs: &Option<Bound<T>>,
+    order: std::cmp::Ordering,
+) -> Option<Bound<T>> {
+    match (lhs, rhs) {
+        (Some(lhs_bound), Some(rhs_bound)) => {
+            match lhs_bound.value.partial_cmp(&rhs_bound.value) {
+                Some(std::cmp::Ordering::Equal) => Option::Some(Bound {
+                    value: lhs_bound.value.clone(),
+                    interval: lhs_bound.interval.intersect(rhs_bound.interval),
+                    side: lhs_bound.side,
+                }),
+                Some(ordering) if ordering == order => Option::Some(lhs_bound.clone()),
+                _ => Option::Some(rhs_bound.clone()),
+            }
+        }
+        _ => Option::None,
+    }
+}
+
 pub struct ValueRange<T: Arithmetic> {
     pub lb: Option<Bound<T>>,
     pub ub: Option<Bound<T>>,
@@ -44,11 +65,47 @@ impl<T: Arithmetic> ValueRange<T> {
         }
     }
 
-    fn empty(&self) -> bool {
-        !self.lb.is_none() && !self.ub.is_none()
+    pub fn empty(&self) -> bool {
+        if let (Some(lower_bound), Some(upper_bound)) = (&self.lb, &self.ub) {
+            match lower_bound.value.partial_cmp(&upper_bound.value) {
+                Some(std::cmp::Ordering::Less) => false,
+                Some(std::cmp::Ordering::Equal) => {
+                    lower_bound.interval == Interval::Open
+                        || upper_bound.interval == Interval::Open
+                        || !matches!(lower_bound.value, ValueWrapper::Value(_))
+                }
+                _ => true,
+            }
+        } else {
+            true
+        }
+    }
+
+    pub fn set_lb(&mut self, value: ValueWrapper<T>, interval: Interval) {
+        self.lb = Option::Some(Bound {
+            value: value,
+            interval: interval,
+            side: BoundSide::Lower,
+        });
     }
 
-    fn fixed(&self) -> bool
+    pub fn set_ub(&mut self, value: ValueWrapper<T>, interval: Interval) {
+        self.ub = Option::Some(Bound {
+            value: value,
+            interval: interval,
+            side: BoundSide::Upper,
+        });
+    }
+
+    // bounds are tightened side by side, the result may be empty
+    pub fn intersect(&self, rhs: &Self) -> Self {
+        Self {
+            lb: tighter(&self.lb, &rhs.lb, std::cmp::Ordering::Greater),
+            ub: tighter(&self.ub, &rhs.ub, std::cmp::Ordering::Less),
+        }
+    }
+
+    pub fn fixed(&self) -> bool
     where
         T: Precision,
     {
@@ -69,6 +126,15 @@ impl<T: Arithmetic> ValueRange<T> {
     }
 }
 
+impl<T: Arithmetic> Clone for ValueRange<T> {
+    fn clone(&self) -> Self {
+        Self {
+            lb: self.lb.clone(),
+            ub: self.ub.clone(),
+        }
+    }
+}
+
 impl<T: Arithmetic> std::fmt::Display for ValueRange<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         
@@ -210,25 +210,27 @@ impl<'a, 'b, S: Shape> DummyAccessIterator<'a, 'b, S> {
     }
 }
 
+#[doc(hidden)]
+#[macro_export]
 macro_rules! dummy_index {
     ($x:literal) => {
-        DummyIndex::from($x as isize)
+        $crate::DummyIndex::from($x as isize)
     };
     ($x:expr) => {
-        DummyIndex::from($x)
+        $crate::DummyIndex::from($x)
     };
 }
 
 #[macro_export]
 macro_rules! dummy {
     ($($x:expr),*) => {
-        [$(dummy_index!($x),)*]
+        [$($crate::dummy_index!($x),)*]
     };
 }
 
 #[macro_export]
 macro_rules! dyn_dummy {
     ($($x:expr),*) => {
-        vec!($(dummy_index!($x),)*)
+        vec!($($crate::dummy_index!($x),)*)
     };
 }
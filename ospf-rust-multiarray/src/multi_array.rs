@@ -39,6 +39,19 @@ impl<T: Sized, S: Shape> MultiArray<T, S> {
         }
     }
 
+    pub fn shape(&self) -> &S {
+        &self.shape
+    }
+
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    // initialized elements, in the order of their flat indexes
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.list.iter().filter_map(|value| value.as_ref())
+    }
+
     pub fn get(&self, vector: S::DummyVectorType) -> Result<Vec<&Option<T>>, OutOfShapeError> {
         let mut ret = Vec::new();
         let policy = DummyAccessPolicy::new(&vector, &self.shape);
@@ -148,6 +161,8 @@ type MultiArray19<T> = MultiArray<T, Shape19>;
 type MultiArray20<T> = MultiArray<T, Shape20>;
 type DynMultiArray<T> = MultiArray<T, DynShape>;
 
+#[doc(hidden)]
+#[macro_export]
 macro_rules! vector_index {
     ($x:literal) => {
         $x as usize
@@ -160,13 +175,13 @@ macro_rules! vector_index {
 #[macro_export]
 macro_rules! vector {
     ($($x:expr),*) => {
-        &[$(vector_index!($x),)*]
+        &[$($crate::vector_index!($x),)*]
     };
 }
 
 #[macro_export]
 macro_rules! dyn_vector {
     ($($x:expr),*) => {
-        vec!($(vector_index!($x),)*)
+        vec!($($crate::vector_index!($x),)*)
     };
 }
@@ -128,7 +128,7 @@ pub trait Shape {
         } else {
             let mut index = 0;
             for i in 0..self.dimension() {
-                if vector[i] > self.len_of_dimension(i).unwrap() {
+                if vector[i] >= self.len_of_dimension(i).unwrap() {
                     return Err(IndexCalculationError::OutOfShape(OutOfShapeError {
                         dimension: i,
                         len: self.len_of_dimension(i).unwrap(),
@@ -290,6 +290,7 @@ shape!(Shape18, 18);
 shape!(Shape19, 19);
 shape!(Shape20, 20);
 
+#[derive(Clone)]
 pub struct DynShape {
     pub(self) shape: Vec<usize>,
     pub(self) offset: Vec<usize>,